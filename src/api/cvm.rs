@@ -1,14 +1,20 @@
 use crate::{
-    Result,
-    client::RequestOptions,
-    types::cvm::{
-        DescribeImagesRequest, DescribeImagesResponse, DescribeInstanceVncUrlRequest,
-        DescribeInstanceVncUrlResponse, DescribeInstancesRequest, DescribeInstancesResponse,
-        GenericActionResponse, ModifyInstancesProjectRequest, RebootInstancesRequest,
-        ResetInstancesPasswordRequest, RunInstancesRequest, RunInstancesResponse,
-        StartInstancesRequest, StopInstancesRequest, TerminateInstancesRequest,
+    Error, Result,
+    client::{RequestOptions, WaitOptions, Waiter},
+    error::{ErrorKind, ObservedState},
+    types::{
+        InstanceId, Region,
+        cvm::{
+            DescribeImagesRequest, DescribeImagesResponse, DescribeInstanceVncUrlRequest,
+            DescribeInstanceVncUrlResponse, DescribeInstancesRequest, DescribeInstancesResponse,
+            GenericActionResponse, InstanceSummary, ModifyInstancesProjectRequest,
+            RebootInstancesRequest, ResetInstancesPasswordRequest, RunInstancesRequest,
+            RunInstancesResponse, StartInstancesRequest, StopInstancesRequest,
+            TerminateInstancesRequest,
+        },
     },
 };
+use std::sync::Arc;
 
 #[cfg(feature = "async")]
 use crate::client::Client;
@@ -174,6 +180,63 @@ impl CvmService {
     ) -> Result<DescribeImagesResponse> {
         self.client.execute(request, Some(options)).await
     }
+
+    /// Polls `DescribeInstances` until every instance is `RUNNING` with no operation in flight.
+    ///
+    /// Returns the final instance summaries, or [`ErrorKind::WaiterTimeout`] carrying the last
+    /// observed states once `options.timeout` elapses. Fails early with
+    /// [`ErrorKind::WaiterFailed`] when an instance reports `LAUNCH_FAILED` or a `FAILED`
+    /// operation. Takes between 1 and 100 instance ids.
+    pub async fn wait_until_running(
+        &self,
+        region: impl Into<Region>,
+        instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+        options: &WaitOptions,
+    ) -> Result<Vec<InstanceSummary>> {
+        let (request, waiter) = instance_waiter(
+            InstanceTarget::Running,
+            region.into(),
+            instance_ids,
+            options,
+        )?;
+        Ok(instance_set(
+            self.client.wait_until(&request, &waiter).await?,
+        ))
+    }
+
+    /// Polls `DescribeInstances` until every instance is `STOPPED` with no operation in flight.
+    pub async fn wait_until_stopped(
+        &self,
+        region: impl Into<Region>,
+        instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+        options: &WaitOptions,
+    ) -> Result<Vec<InstanceSummary>> {
+        let (request, waiter) = instance_waiter(
+            InstanceTarget::Stopped,
+            region.into(),
+            instance_ids,
+            options,
+        )?;
+        Ok(instance_set(
+            self.client.wait_until(&request, &waiter).await?,
+        ))
+    }
+
+    /// Polls `DescribeInstances` until every instance is `TERMINATED` or no longer listed.
+    pub async fn wait_until_terminated(
+        &self,
+        region: impl Into<Region>,
+        instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+        options: &WaitOptions,
+    ) -> Result<()> {
+        let (request, waiter) = instance_waiter(
+            InstanceTarget::Terminated,
+            region.into(),
+            instance_ids,
+            options,
+        )?;
+        self.client.wait_until(&request, &waiter).await.map(|_| ())
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -334,4 +397,173 @@ impl BlockingCvmService {
     ) -> Result<DescribeImagesResponse> {
         self.client.execute(request, Some(options))
     }
+
+    /// Polls `DescribeInstances` until every instance is `RUNNING` with no operation in flight.
+    ///
+    /// Returns the final instance summaries, or [`ErrorKind::WaiterTimeout`] carrying the last
+    /// observed states once `options.timeout` elapses. Fails early with
+    /// [`ErrorKind::WaiterFailed`] when an instance reports `LAUNCH_FAILED` or a `FAILED`
+    /// operation. Takes between 1 and 100 instance ids.
+    pub fn wait_until_running(
+        &self,
+        region: impl Into<Region>,
+        instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+        options: &WaitOptions,
+    ) -> Result<Vec<InstanceSummary>> {
+        let (request, waiter) = instance_waiter(
+            InstanceTarget::Running,
+            region.into(),
+            instance_ids,
+            options,
+        )?;
+        Ok(instance_set(self.client.wait_until(&request, &waiter)?))
+    }
+
+    /// Polls `DescribeInstances` until every instance is `STOPPED` with no operation in flight.
+    pub fn wait_until_stopped(
+        &self,
+        region: impl Into<Region>,
+        instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+        options: &WaitOptions,
+    ) -> Result<Vec<InstanceSummary>> {
+        let (request, waiter) = instance_waiter(
+            InstanceTarget::Stopped,
+            region.into(),
+            instance_ids,
+            options,
+        )?;
+        Ok(instance_set(self.client.wait_until(&request, &waiter)?))
+    }
+
+    /// Polls `DescribeInstances` until every instance is `TERMINATED` or no longer listed.
+    pub fn wait_until_terminated(
+        &self,
+        region: impl Into<Region>,
+        instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+        options: &WaitOptions,
+    ) -> Result<()> {
+        let (request, waiter) = instance_waiter(
+            InstanceTarget::Terminated,
+            region.into(),
+            instance_ids,
+            options,
+        )?;
+        self.client.wait_until(&request, &waiter).map(|_| ())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum InstanceTarget {
+    Running,
+    Stopped,
+    Terminated,
+}
+
+impl InstanceTarget {
+    fn waiter(self) -> &'static str {
+        match self {
+            InstanceTarget::Running => "InstanceRunning",
+            InstanceTarget::Stopped => "InstanceStopped",
+            InstanceTarget::Terminated => "InstanceTerminated",
+        }
+    }
+
+    fn state(self) -> &'static str {
+        match self {
+            InstanceTarget::Running => "RUNNING",
+            InstanceTarget::Stopped => "STOPPED",
+            InstanceTarget::Terminated => "TERMINATED",
+        }
+    }
+
+    fn reached(self, instance: Option<&InstanceSummary>) -> bool {
+        let Some(instance) = instance else {
+            return matches!(self, InstanceTarget::Terminated);
+        };
+        instance.instance_state.as_deref() == Some(self.state())
+            && instance.latest_operation_state.as_deref() != Some("OPERATING")
+    }
+}
+
+/// `DescribeInstances` accepts at most 100 instance ids per call.
+const MAX_WAITER_INSTANCE_IDS: usize = 100;
+
+fn failed(instance: Option<&InstanceSummary>) -> bool {
+    instance.is_some_and(|instance| {
+        instance.latest_operation_state.as_deref() == Some("FAILED")
+            || instance.instance_state.as_deref() == Some("LAUNCH_FAILED")
+    })
+}
+
+fn instance_waiter(
+    target: InstanceTarget,
+    region: Region,
+    instance_ids: impl IntoIterator<Item = impl Into<InstanceId>>,
+    options: &WaitOptions,
+) -> Result<(DescribeInstancesRequest, Waiter<DescribeInstancesResponse>)> {
+    let ids: Arc<[InstanceId]> = instance_ids.into_iter().map(Into::into).collect();
+    if ids.is_empty() {
+        return Err(Error::invalid_config(
+            "waiting on instances requires at least one instance id",
+        ));
+    }
+    if ids.len() > MAX_WAITER_INSTANCE_IDS {
+        return Err(Error::invalid_config(format!(
+            "waiting on {} instances exceeds the limit of {MAX_WAITER_INSTANCE_IDS} per call",
+            ids.len()
+        )));
+    }
+    let request = ids.iter().cloned().fold(
+        DescribeInstancesRequest::new()
+            .region(region)
+            .limit(ids.len() as u32),
+        |request, id| request.push_instance_id(id),
+    );
+
+    let failure_ids = Arc::clone(&ids);
+    let observed_ids = Arc::clone(&ids);
+    let mut waiter = Waiter::new(target.waiter())
+        .options(options.clone())
+        .success(move |resp: &DescribeInstancesResponse| {
+            ids.iter().all(|id| target.reached(find_instance(resp, id)))
+        })
+        .failure(move |resp: &DescribeInstancesResponse| {
+            failure_ids.iter().any(|id| failed(find_instance(resp, id)))
+        })
+        .observe(move |resp| {
+            observed_ids
+                .iter()
+                .map(|id| {
+                    let instance = find_instance(resp, id);
+                    ObservedState::new(
+                        id.as_str(),
+                        instance.and_then(|instance| instance.instance_state.clone()),
+                        instance.and_then(|instance| instance.latest_operation_state.clone()),
+                    )
+                })
+                .collect()
+        });
+    if matches!(target, InstanceTarget::Terminated) {
+        // Terminated instances eventually disappear and their ids stop resolving.
+        waiter = waiter.success_on_error(|err| {
+            err.kind() == ErrorKind::NotFound
+                || err.code().is_some_and(|code| code.ends_with("NotFound"))
+        });
+    }
+    Ok((request, waiter))
+}
+
+fn find_instance<'a>(
+    resp: &'a DescribeInstancesResponse,
+    id: &InstanceId,
+) -> Option<&'a InstanceSummary> {
+    resp.response
+        .instance_set
+        .iter()
+        .find(|instance| instance.instance_id.as_ref() == Some(id))
+}
+
+fn instance_set(resp: Option<DescribeInstancesResponse>) -> Vec<InstanceSummary> {
+    resp.map(|resp| resp.response.instance_set)
+        .unwrap_or_default()
 }
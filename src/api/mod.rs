@@ -7,5 +7,6 @@ pub mod cdn;
 pub mod cvm;
 pub mod dns;
 pub mod ssl;
+pub mod sts;
 pub mod tag;
 pub mod vpc;
@@ -1,10 +1,14 @@
 use crate::{
     Result,
-    client::RequestOptions,
-    types::ssl::{
-        ApplyCertificateRequest, ApplyCertificateResponse, DescribeCertificateRequest,
-        DescribeCertificateResponse, DownloadCertificateRequest, DownloadCertificateResponse,
-        UploadCertificateRequest, UploadCertificateResponse,
+    client::{RequestOptions, WaitOptions, Waiter},
+    error::ObservedState,
+    types::{
+        CertificateId,
+        ssl::{
+            ApplyCertificateRequest, ApplyCertificateResponse, DescribeCertificateRequest,
+            DescribeCertificateResponse, DownloadCertificateRequest, DownloadCertificateResponse,
+            UploadCertificateRequest, UploadCertificateResponse,
+        },
     },
 };
 
@@ -82,6 +86,21 @@ impl SslService {
     ) -> Result<UploadCertificateResponse> {
         self.client.execute(request, Some(options)).await
     }
+
+    /// Polls `DescribeCertificate` until the certificate is issued (`Status` 1).
+    ///
+    /// Fails with [`ErrorKind::WaiterFailed`](crate::error::ErrorKind::WaiterFailed) when review
+    /// fails or the certificate is expired, cancelled or revoked.
+    pub async fn wait_until_certificate_issued(
+        &self,
+        certificate_id: impl Into<CertificateId>,
+        options: &WaitOptions,
+    ) -> Result<()> {
+        let certificate_id = certificate_id.into();
+        let waiter = certificate_issued_waiter(certificate_id.as_str(), options);
+        let request = DescribeCertificateRequest::new(certificate_id);
+        self.client.wait_until(&request, &waiter).await.map(|_| ())
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -158,4 +177,44 @@ impl BlockingSslService {
     ) -> Result<UploadCertificateResponse> {
         self.client.execute(request, Some(options))
     }
+
+    /// Polls `DescribeCertificate` until the certificate is issued (`Status` 1).
+    ///
+    /// Fails with [`ErrorKind::WaiterFailed`](crate::error::ErrorKind::WaiterFailed) when review
+    /// fails or the certificate is expired, cancelled or revoked.
+    pub fn wait_until_certificate_issued(
+        &self,
+        certificate_id: impl Into<CertificateId>,
+        options: &WaitOptions,
+    ) -> Result<()> {
+        let certificate_id = certificate_id.into();
+        let waiter = certificate_issued_waiter(certificate_id.as_str(), options);
+        let request = DescribeCertificateRequest::new(certificate_id);
+        self.client.wait_until(&request, &waiter).map(|_| ())
+    }
+}
+
+/// `Status` values after which a certificate can no longer be issued.
+const CERTIFICATE_FAILED_STATUSES: [i32; 4] = [2, 3, 7, 10];
+
+fn certificate_issued_waiter(
+    certificate_id: &str,
+    options: &WaitOptions,
+) -> Waiter<DescribeCertificateResponse> {
+    let certificate_id = certificate_id.to_string();
+    Waiter::new("CertificateIssued")
+        .options(options.clone())
+        .success(|resp: &DescribeCertificateResponse| resp.response.status == Some(1))
+        .failure(|resp| {
+            resp.response
+                .status
+                .is_some_and(|status| CERTIFICATE_FAILED_STATUSES.contains(&status))
+        })
+        .observe(move |resp| {
+            vec![ObservedState::new(
+                certificate_id.clone(),
+                resp.response.status.map(|status| status.to_string()),
+                resp.response.status_msg.clone(),
+            )]
+        })
 }
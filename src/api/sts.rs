@@ -0,0 +1,127 @@
+use crate::{
+    Result,
+    client::RequestOptions,
+    types::sts::{
+        AssumeRoleRequest, AssumeRoleWithWebIdentityRequest, GetFederationTokenRequest,
+        TemporaryCredentialsResponse,
+    },
+};
+
+#[cfg(feature = "async")]
+use crate::client::Client;
+
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct StsService {
+    client: Client,
+}
+
+#[cfg(feature = "async")]
+impl StsService {
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    pub async fn assume_role(
+        &self,
+        request: &AssumeRoleRequest,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, None).await
+    }
+
+    pub async fn assume_role_with_options(
+        &self,
+        request: &AssumeRoleRequest,
+        options: &RequestOptions,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, Some(options)).await
+    }
+
+    pub async fn get_federation_token(
+        &self,
+        request: &GetFederationTokenRequest,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, None).await
+    }
+
+    pub async fn get_federation_token_with_options(
+        &self,
+        request: &GetFederationTokenRequest,
+        options: &RequestOptions,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, Some(options)).await
+    }
+
+    pub async fn assume_role_with_web_identity(
+        &self,
+        request: &AssumeRoleWithWebIdentityRequest,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, None).await
+    }
+
+    pub async fn assume_role_with_web_identity_with_options(
+        &self,
+        request: &AssumeRoleWithWebIdentityRequest,
+        options: &RequestOptions,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, Some(options)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+use crate::client::BlockingClient;
+
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BlockingStsService {
+    client: BlockingClient,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingStsService {
+    pub(crate) fn new(client: BlockingClient) -> Self {
+        Self { client }
+    }
+
+    pub fn assume_role(&self, request: &AssumeRoleRequest) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, None)
+    }
+
+    pub fn assume_role_with_options(
+        &self,
+        request: &AssumeRoleRequest,
+        options: &RequestOptions,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, Some(options))
+    }
+
+    pub fn get_federation_token(
+        &self,
+        request: &GetFederationTokenRequest,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, None)
+    }
+
+    pub fn get_federation_token_with_options(
+        &self,
+        request: &GetFederationTokenRequest,
+        options: &RequestOptions,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, Some(options))
+    }
+
+    pub fn assume_role_with_web_identity(
+        &self,
+        request: &AssumeRoleWithWebIdentityRequest,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, None)
+    }
+
+    pub fn assume_role_with_web_identity_with_options(
+        &self,
+        request: &AssumeRoleWithWebIdentityRequest,
+        options: &RequestOptions,
+    ) -> Result<TemporaryCredentialsResponse> {
+        self.client.execute(request, Some(options))
+    }
+}
@@ -0,0 +1,199 @@
+use super::{Auth, Credentials, CredentialsProvider};
+use crate::{
+    Error,
+    client::EndpointMode,
+    types::{Region, sts::AssumeRoleRequest},
+};
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "async")]
+use super::CredentialsFuture;
+
+pub(super) const DEFAULT_STS_BASE_URL: &str = "https://tencentcloudapi.com";
+
+/// Temporary credentials for `role_arn`, obtained with `sts:AssumeRole` using the credentials
+/// of a base provider.
+///
+/// Used through [`Auth::provider`], the credentials are cached by the client and the role is
+/// assumed again shortly before `ExpiredTime`.
+#[derive(Clone)]
+pub struct AssumeRoleProvider {
+    base: Arc<dyn CredentialsProvider>,
+    role_arn: String,
+    session_name: String,
+    duration: Option<Duration>,
+    policy: Option<String>,
+    external_id: Option<String>,
+    region: Option<Region>,
+    base_url: String,
+    endpoint_mode: EndpointMode,
+}
+
+impl fmt::Debug for AssumeRoleProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssumeRoleProvider")
+            .field("role_arn", &self.role_arn)
+            .field("session_name", &self.session_name)
+            .field("duration", &self.duration)
+            .field("has_policy", &self.policy.is_some())
+            .field("region", &self.region)
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl AssumeRoleProvider {
+    pub fn new(base: impl CredentialsProvider + 'static, role_arn: impl Into<String>) -> Self {
+        Self {
+            base: Arc::new(base),
+            role_arn: role_arn.into(),
+            session_name: default_session_name(),
+            duration: None,
+            policy: None,
+            external_id: None,
+            region: None,
+            base_url: DEFAULT_STS_BASE_URL.to_string(),
+            endpoint_mode: EndpointMode::ServiceSubdomain,
+        }
+    }
+
+    /// `RoleSessionName` recorded in CloudAudit (defaults to `tencent-sdk-<unix time>`).
+    pub fn session_name(mut self, session_name: impl Into<String>) -> Self {
+        self.session_name = session_name.into();
+        self
+    }
+
+    /// Lifetime of each set of credentials; whole seconds are sent as `DurationSeconds`.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Inline policy (JSON) further restricting what the role session may do.
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn region(mut self, region: impl Into<Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Sends `AssumeRole` to `base_url` instead of `https://tencentcloudapi.com`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn endpoint_mode(mut self, mode: EndpointMode) -> Self {
+        self.endpoint_mode = mode;
+        self
+    }
+
+    fn request(&self) -> AssumeRoleRequest {
+        let mut request =
+            AssumeRoleRequest::new(self.role_arn.as_str(), self.session_name.as_str());
+        if let Some(region) = &self.region {
+            request = request.region(region.clone());
+        }
+        if let Some(duration) = self.duration {
+            request = request.duration_seconds(duration.as_secs());
+        }
+        if let Some(policy) = &self.policy {
+            request = request.policy(policy.as_str());
+        }
+        if let Some(external_id) = &self.external_id {
+            request = request.external_id(external_id.as_str());
+        }
+        request
+    }
+
+    #[cfg(feature = "blocking")]
+    fn assume_role(&self) -> Result<Credentials, Error> {
+        let client = crate::BlockingClient::builder(&self.base_url)?
+            .endpoint_mode(self.endpoint_mode)
+            .auth(Auth::Provider(self.base.clone()))
+            .build()?;
+        let response = client.sts().assume_role(&self.request())?;
+        Ok(response.response.to_credentials())
+    }
+
+    #[cfg(feature = "async")]
+    async fn assume_role_async(&self) -> Result<Credentials, Error> {
+        let client = crate::Client::builder(&self.base_url)?
+            .endpoint_mode(self.endpoint_mode)
+            .auth(Auth::Provider(self.base.clone()))
+            .build()?;
+        let response = client.sts().assume_role(&self.request()).await?;
+        Ok(response.response.to_credentials())
+    }
+}
+
+/// `tencent-sdk-<unix time>`, used when no role session name is configured.
+pub(super) fn default_session_name() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    format!("tencent-sdk-{now}")
+}
+
+impl CredentialsProvider for AssumeRoleProvider {
+    #[cfg(feature = "blocking")]
+    fn credentials(&self) -> Result<Credentials, Error> {
+        self.assume_role()
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    fn credentials(&self) -> Result<Credentials, Error> {
+        Err(Error::invalid_config(
+            "AssumeRoleProvider needs the `blocking` feature for synchronous lookups",
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    fn credentials_async(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.assume_role_async())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::endpoint::Endpoint;
+    use serde_json::json;
+
+    #[test]
+    fn builds_assume_role_request_from_options() {
+        let provider = AssumeRoleProvider::new(
+            Credentials::new("AKIDbase", "base-key"),
+            "qcs::cam::uin/100:roleName/ops",
+        )
+        .session_name("cross-account")
+        .duration(Duration::from_secs(900))
+        .external_id("ext-1")
+        .region("ap-guangzhou");
+
+        let request = provider.request();
+        let payload = request.payload().unwrap().unwrap();
+
+        assert_eq!(
+            Endpoint::region(&request).map(Region::as_str),
+            Some("ap-guangzhou")
+        );
+        assert_eq!(payload["RoleSessionName"], json!("cross-account"));
+        assert_eq!(payload["DurationSeconds"], json!(900));
+        assert_eq!(payload["ExternalId"], json!("ext-1"));
+        assert!(payload.get("Policy").is_none());
+    }
+}
@@ -0,0 +1,205 @@
+use super::{Auth, Credentials};
+use crate::{Error, signing::SigningError};
+use std::{
+    sync::{Mutex, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+/// Credentials are refreshed this long before they expire.
+pub(crate) const DEFAULT_REFRESH_WINDOW: Duration = Duration::from_secs(300);
+
+/// Short-lived credentials are refreshed once this fraction of their lifetime is left, so they
+/// are still served from the cache when the lifetime is shorter than the refresh window.
+const MAX_REFRESH_WINDOW_DIVISOR: u32 = 4;
+
+pub(crate) struct CredentialsCache {
+    auth: Auth,
+    refresh_window: Duration,
+    cached: Mutex<Option<CachedCredentials>>,
+    /// Held while the provider is called, so concurrent misses wait for one refresh.
+    #[cfg(feature = "blocking")]
+    refresh: Mutex<()>,
+    #[cfg(feature = "async")]
+    refresh_async: tokio::sync::Mutex<()>,
+}
+
+struct CachedCredentials {
+    credentials: Credentials,
+    refresh_window: Duration,
+}
+
+impl CredentialsCache {
+    pub(crate) fn new(auth: Auth) -> Self {
+        Self {
+            auth,
+            refresh_window: DEFAULT_REFRESH_WINDOW,
+            cached: Mutex::new(None),
+            #[cfg(feature = "blocking")]
+            refresh: Mutex::new(()),
+            #[cfg(feature = "async")]
+            refresh_async: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    pub(crate) fn credentials(&self) -> Result<Credentials, Error> {
+        match &self.auth {
+            Auth::Tc3(auth) => Ok(auth.to_credentials()),
+            Auth::Provider(provider) => {
+                if let Some(credentials) = self.cached() {
+                    return Ok(credentials);
+                }
+                let _refresh = self.refresh.lock().unwrap_or_else(PoisonError::into_inner);
+                if let Some(credentials) = self.cached() {
+                    return Ok(credentials);
+                }
+                let credentials = provider.credentials()?;
+                self.store(&credentials);
+                Ok(credentials)
+            }
+            Auth::None => Err(missing_credentials()),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn credentials_async(&self) -> Result<Credentials, Error> {
+        match &self.auth {
+            Auth::Tc3(auth) => Ok(auth.to_credentials()),
+            Auth::Provider(provider) => {
+                if let Some(credentials) = self.cached() {
+                    return Ok(credentials);
+                }
+                let _refresh = self.refresh_async.lock().await;
+                if let Some(credentials) = self.cached() {
+                    return Ok(credentials);
+                }
+                let credentials = provider.credentials_async().await?;
+                self.store(&credentials);
+                Ok(credentials)
+            }
+            Auth::None => Err(missing_credentials()),
+        }
+    }
+
+    fn cached(&self) -> Option<Credentials> {
+        let cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        cached
+            .as_ref()
+            .filter(|cached| !cached.credentials.expires_within(cached.refresh_window))
+            .map(|cached| cached.credentials.clone())
+    }
+
+    fn store(&self, credentials: &Credentials) {
+        let lifetime = credentials.expires_at().map(|expires_at| {
+            expires_at
+                .duration_since(SystemTime::now())
+                .unwrap_or_default()
+        });
+        let refresh_window = lifetime.map_or(self.refresh_window, |lifetime| {
+            self.refresh_window
+                .min(lifetime / MAX_REFRESH_WINDOW_DIVISOR)
+        });
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        *cached = Some(CachedCredentials {
+            credentials: credentials.clone(),
+            refresh_window,
+        });
+    }
+}
+
+fn missing_credentials() -> Error {
+    Error::signing(Box::new(SigningError::MissingCredentials))
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use super::*;
+    use crate::auth::CredentialsProvider;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use std::time::SystemTime;
+
+    struct CountingProvider {
+        calls: Arc<AtomicUsize>,
+        lifetime: Duration,
+    }
+
+    impl CredentialsProvider for CountingProvider {
+        fn credentials(&self) -> Result<Credentials, Error> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Credentials::new(format!("id-{call}"), "key")
+                .with_expiry(SystemTime::now() + self.lifetime))
+        }
+    }
+
+    #[test]
+    fn caches_credentials_until_refresh_window() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cache = CredentialsCache::new(Auth::provider(CountingProvider {
+            calls: calls.clone(),
+            lifetime: Duration::from_secs(3600),
+        }));
+
+        assert_eq!(cache.credentials().unwrap().secret_id(), "id-0");
+        assert_eq!(cache.credentials().unwrap().secret_id(), "id-0");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn refreshes_expired_credentials() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cache = CredentialsCache::new(Auth::provider(CountingProvider {
+            calls: calls.clone(),
+            lifetime: Duration::ZERO,
+        }));
+
+        assert_eq!(cache.credentials().unwrap().secret_id(), "id-0");
+        assert_eq!(cache.credentials().unwrap().secret_id(), "id-1");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn caches_credentials_shorter_lived_than_refresh_window() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cache = CredentialsCache::new(Auth::provider(CountingProvider {
+            calls: calls.clone(),
+            lifetime: Duration::from_secs(60),
+        }));
+
+        assert_eq!(cache.credentials().unwrap().secret_id(), "id-0");
+        assert_eq!(cache.credentials().unwrap().secret_id(), "id-0");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn concurrent_misses_share_one_refresh() {
+        struct SlowProvider(Arc<AtomicUsize>);
+
+        impl CredentialsProvider for SlowProvider {
+            fn credentials(&self) -> Result<Credentials, Error> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(50));
+                Ok(Credentials::new("id", "key")
+                    .with_expiry(SystemTime::now() + Duration::from_secs(3600)))
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cache = CredentialsCache::new(Auth::provider(SlowProvider(calls.clone())));
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| cache.credentials().unwrap());
+            }
+        });
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn missing_credentials_is_a_config_error() {
+        let cache = CredentialsCache::new(Auth::none());
+        let err = cache.credentials().unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::InvalidConfig);
+    }
+}
@@ -0,0 +1,316 @@
+use super::{
+    Credentials, CredentialsProvider, CvmRoleProvider, EnvCredentialsProvider, ProfileProvider,
+    WebIdentityProvider, credentials_in_env,
+};
+use crate::Error;
+use std::{
+    error::Error as _,
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+#[cfg(feature = "async")]
+use super::CredentialsFuture;
+
+/// Where [`DefaultCredentialsProvider`] found credentials.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CredentialSource {
+    Environment,
+    Profile,
+    WebIdentity,
+    CvmRole,
+}
+
+impl CredentialSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CredentialSource::Environment => "environment",
+            CredentialSource::Profile => "profile",
+            CredentialSource::WebIdentity => "web identity",
+            CredentialSource::CvmRole => "CVM role",
+        }
+    }
+}
+
+impl fmt::Display for CredentialSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+type Candidate = (
+    CredentialSource,
+    Result<Arc<dyn CredentialsProvider>, Error>,
+);
+
+/// Tries, in order: environment variables ([`EnvCredentialsProvider`]), the `tccli` profile
+/// ([`ProfileProvider::from_env`]), TKE OIDC ([`WebIdentityProvider::from_env`]) and the CVM
+/// instance role ([`CvmRoleProvider`]).
+///
+/// The first source that yields credentials is remembered and used for later refreshes. When
+/// every source fails, the `InvalidConfig` error lists why each one was skipped. Credential
+/// variables that are set but incomplete, e.g. a secret id without its key, fail the chain
+/// instead of falling through to another identity.
+pub struct DefaultCredentialsProvider {
+    candidates: fn() -> Vec<Candidate>,
+    resolved: Mutex<Option<(CredentialSource, Arc<dyn CredentialsProvider>)>>,
+}
+
+impl fmt::Debug for DefaultCredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefaultCredentialsProvider")
+            .field("source", &self.source())
+            .finish()
+    }
+}
+
+impl Default for DefaultCredentialsProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DefaultCredentialsProvider {
+    pub fn new() -> Self {
+        Self::with_candidates(default_candidates)
+    }
+
+    fn with_candidates(candidates: fn() -> Vec<Candidate>) -> Self {
+        Self {
+            candidates,
+            resolved: Mutex::new(None),
+        }
+    }
+
+    /// The source that provided credentials, once one has been found.
+    pub fn source(&self) -> Option<CredentialSource> {
+        self.resolved().map(|(source, _)| source)
+    }
+
+    fn resolved(&self) -> Option<(CredentialSource, Arc<dyn CredentialsProvider>)> {
+        let resolved = self.resolved.lock().unwrap_or_else(PoisonError::into_inner);
+        resolved.clone()
+    }
+
+    fn remember(&self, source: CredentialSource, provider: Arc<dyn CredentialsProvider>) {
+        let mut resolved = self.resolved.lock().unwrap_or_else(PoisonError::into_inner);
+        *resolved = Some((source, provider));
+    }
+
+    fn resolve(&self) -> Result<Credentials, Error> {
+        if let Some((_, provider)) = self.resolved() {
+            return provider.credentials();
+        }
+
+        let mut failures = Vec::new();
+        for (source, provider) in (self.candidates)() {
+            let provider = match provider {
+                Ok(provider) => provider,
+                Err(err) => {
+                    failures.push((source, err));
+                    continue;
+                }
+            };
+            match provider.credentials() {
+                Ok(credentials) => {
+                    self.remember(source, provider);
+                    return Ok(credentials);
+                }
+                Err(err) if source == CredentialSource::Environment => return Err(err),
+                Err(err) => failures.push((source, err)),
+            }
+        }
+        Err(chain_error(&failures))
+    }
+
+    #[cfg(feature = "async")]
+    async fn resolve_async(&self) -> Result<Credentials, Error> {
+        if let Some((_, provider)) = self.resolved() {
+            return provider.credentials_async().await;
+        }
+
+        let mut failures = Vec::new();
+        for (source, provider) in (self.candidates)() {
+            let provider = match provider {
+                Ok(provider) => provider,
+                Err(err) => {
+                    failures.push((source, err));
+                    continue;
+                }
+            };
+            match provider.credentials_async().await {
+                Ok(credentials) => {
+                    self.remember(source, provider);
+                    return Ok(credentials);
+                }
+                Err(err) if source == CredentialSource::Environment => return Err(err),
+                Err(err) => failures.push((source, err)),
+            }
+        }
+        Err(chain_error(&failures))
+    }
+}
+
+impl CredentialsProvider for DefaultCredentialsProvider {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        self.resolve()
+    }
+
+    #[cfg(feature = "async")]
+    fn credentials_async(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.resolve_async())
+    }
+}
+
+fn default_candidates() -> Vec<Candidate> {
+    vec![
+        (CredentialSource::Environment, env_candidate()),
+        (
+            CredentialSource::Profile,
+            Ok(Arc::new(ProfileProvider::from_env())),
+        ),
+        (
+            CredentialSource::WebIdentity,
+            WebIdentityProvider::from_env()
+                .map(|provider| Arc::new(provider) as Arc<dyn CredentialsProvider>),
+        ),
+        (
+            CredentialSource::CvmRole,
+            Ok(Arc::new(CvmRoleProvider::new())),
+        ),
+    ]
+}
+
+/// The environment only counts as a source once a credential variable is set; its provider then
+/// fails the chain on partial configuration rather than being skipped.
+fn env_candidate() -> Result<Arc<dyn CredentialsProvider>, Error> {
+    let provider = EnvCredentialsProvider::new();
+    if !credentials_in_env() {
+        // Fails with the variables to set.
+        provider.credentials()?;
+    }
+    Ok(Arc::new(provider))
+}
+
+fn chain_error(failures: &[(CredentialSource, Error)]) -> Error {
+    let reasons = failures
+        .iter()
+        .map(|(source, err)| {
+            let reason = err
+                .message()
+                .map_or_else(|| err.to_string(), str::to_string);
+            match err.source() {
+                Some(cause) => format!("{source}: {reason} ({cause})"),
+                None => format!("{source}: {reason}"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    Error::invalid_config(format!(
+        "no credentials found in the default chain: {reasons}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Failing(&'static str);
+
+    impl CredentialsProvider for Failing {
+        fn credentials(&self) -> Result<Credentials, Error> {
+            Err(Error::invalid_config(self.0))
+        }
+    }
+
+    fn profile_wins() -> Vec<Candidate> {
+        vec![
+            (
+                CredentialSource::Environment,
+                Err(Error::invalid_config("no credentials in environment")),
+            ),
+            (
+                CredentialSource::Profile,
+                Ok(Arc::new(Credentials::new("AKIDprofile", "key"))),
+            ),
+            (
+                CredentialSource::CvmRole,
+                Ok(Arc::new(Failing("must not be reached"))),
+            ),
+        ]
+    }
+
+    fn nothing_works() -> Vec<Candidate> {
+        vec![
+            (
+                CredentialSource::Environment,
+                Err(Error::invalid_config("no credentials in environment")),
+            ),
+            (
+                CredentialSource::WebIdentity,
+                Err(Error::invalid_config(
+                    "environment variable TKE_PROVIDER_ID is not set",
+                )),
+            ),
+        ]
+    }
+
+    fn partial_environment() -> Vec<Candidate> {
+        vec![
+            (
+                CredentialSource::Environment,
+                Ok(Arc::new(Failing(
+                    "environment variable TENCENTCLOUD_SECRET_ID is set but TENCENTCLOUD_SECRET_KEY is missing",
+                ))),
+            ),
+            (
+                CredentialSource::Profile,
+                Ok(Arc::new(Credentials::new("AKIDprofile", "key"))),
+            ),
+        ]
+    }
+
+    #[test]
+    fn returns_first_working_source_and_remembers_it() {
+        let chain = DefaultCredentialsProvider::with_candidates(profile_wins);
+        assert_eq!(chain.source(), None);
+
+        let credentials = chain.credentials().unwrap();
+        assert_eq!(credentials.secret_id(), "AKIDprofile");
+        assert_eq!(chain.source(), Some(CredentialSource::Profile));
+        assert_eq!(chain.credentials().unwrap().secret_id(), "AKIDprofile");
+    }
+
+    #[test]
+    fn lists_why_each_source_failed() {
+        let chain = DefaultCredentialsProvider::with_candidates(nothing_works);
+        let err = chain.credentials().unwrap_err();
+
+        assert_eq!(err.kind(), crate::error::ErrorKind::InvalidConfig);
+        assert_eq!(
+            err.message(),
+            Some(
+                "no credentials found in the default chain: \
+                 environment: no credentials in environment; \
+                 web identity: environment variable TKE_PROVIDER_ID is not set"
+            )
+        );
+        assert_eq!(chain.source(), None);
+    }
+
+    #[test]
+    fn partial_environment_does_not_fall_through() {
+        let chain = DefaultCredentialsProvider::with_candidates(partial_environment);
+        let err = chain.credentials().unwrap_err();
+
+        assert_eq!(err.kind(), crate::error::ErrorKind::InvalidConfig);
+        assert_eq!(
+            err.message(),
+            Some(
+                "environment variable TENCENTCLOUD_SECRET_ID is set but TENCENTCLOUD_SECRET_KEY is missing"
+            )
+        );
+        assert_eq!(chain.source(), None);
+    }
+}
@@ -0,0 +1,278 @@
+use super::{Credentials, CredentialsProvider};
+use crate::{
+    Error,
+    client::config::DEFAULT_USER_AGENT,
+    transport::{TransportConfig, TransportResponse},
+};
+use serde::Deserialize;
+use std::time::{Duration, UNIX_EPOCH};
+use url::Url;
+
+#[cfg(feature = "async")]
+use super::CredentialsFuture;
+
+pub const DEFAULT_METADATA_BASE_URL: &str = "http://metadata.tencentyun.com";
+
+const CREDENTIALS_PATH: &str = "latest/meta-data/cam/security-credentials/";
+const METADATA_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+const METADATA_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Temporary credentials of the CAM role bound to the current CVM instance.
+///
+/// Credentials are read from the instance metadata service and carry the role's session token
+/// and expiry, so the client refreshes them shortly before they expire. When no role is set,
+/// the role bound to the instance is discovered from the metadata service.
+#[derive(Debug, Clone)]
+pub struct CvmRoleProvider {
+    base_url: String,
+    role: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RoleCredentials {
+    #[serde(rename = "TmpSecretId")]
+    tmp_secret_id: String,
+    #[serde(rename = "TmpSecretKey")]
+    tmp_secret_key: String,
+    #[serde(rename = "Token")]
+    token: String,
+    #[serde(rename = "ExpiredTime")]
+    expired_time: u64,
+    #[serde(rename = "Code", default)]
+    code: Option<String>,
+}
+
+impl Default for CvmRoleProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CvmRoleProvider {
+    pub fn new() -> Self {
+        Self {
+            base_url: DEFAULT_METADATA_BASE_URL.to_string(),
+            role: None,
+        }
+    }
+
+    /// Uses `role` instead of discovering the role bound to the instance.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    /// Overrides the metadata service location (defaults to `http://metadata.tencentyun.com`).
+    pub fn metadata_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    fn url(&self, role: Option<&str>) -> Result<Url, Error> {
+        let base = self.base_url.trim_end_matches('/');
+        let url = format!("{base}/{CREDENTIALS_PATH}{}", role.unwrap_or_default());
+        Url::parse(&url)
+            .map_err(|source| Error::invalid_base_url(self.base_url.clone(), Box::new(source)))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn fetch(&self) -> Result<Credentials, Error> {
+        use crate::transport::{
+            BlockingTransport, TransportRequest, blocking_transport::UreqBlockingTransport,
+        };
+
+        let transport = UreqBlockingTransport::new(&transport_config())?;
+        let get = |url: Url| {
+            transport.send(TransportRequest::new(
+                http::Method::GET,
+                url,
+                http::HeaderMap::new(),
+                None,
+                METADATA_TIMEOUT,
+            ))
+        };
+
+        let role = match &self.role {
+            Some(role) => role.clone(),
+            None => role_from_response(get(self.url(None)?)?)?,
+        };
+        credentials_from_response(&role, get(self.url(Some(&role))?)?)
+    }
+
+    #[cfg(feature = "async")]
+    async fn fetch_async(&self) -> Result<Credentials, Error> {
+        use crate::transport::{
+            AsyncTransport, TransportRequest, async_transport::ReqwestAsyncTransport,
+        };
+
+        let transport = ReqwestAsyncTransport::new(&transport_config())?;
+        let get = |url: Url| {
+            transport.send(TransportRequest::new(
+                http::Method::GET,
+                url,
+                http::HeaderMap::new(),
+                None,
+                METADATA_TIMEOUT,
+            ))
+        };
+
+        let role = match &self.role {
+            Some(role) => role.clone(),
+            None => role_from_response(get(self.url(None)?).await?)?,
+        };
+        credentials_from_response(&role, get(self.url(Some(&role))?).await?)
+    }
+}
+
+impl CredentialsProvider for CvmRoleProvider {
+    #[cfg(feature = "blocking")]
+    fn credentials(&self) -> Result<Credentials, Error> {
+        self.fetch()
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    fn credentials(&self) -> Result<Credentials, Error> {
+        Err(Error::invalid_config(
+            "CvmRoleProvider needs the `blocking` feature for synchronous lookups",
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    fn credentials_async(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.fetch_async())
+    }
+}
+
+fn transport_config() -> TransportConfig {
+    TransportConfig {
+        user_agent: DEFAULT_USER_AGENT.to_string(),
+        accept_invalid_certs: false,
+        no_proxy: true,
+        connect_timeout: METADATA_CONNECT_TIMEOUT,
+        read_timeout: None,
+    }
+}
+
+fn role_from_response(response: TransportResponse) -> Result<String, Error> {
+    if !response.status.is_success() {
+        return Err(Error::invalid_config(format!(
+            "metadata service returned {} while discovering the instance role",
+            response.status
+        )));
+    }
+
+    response
+        .body
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| Error::invalid_config("no CAM role is bound to this CVM instance"))
+}
+
+fn credentials_from_response(
+    role: &str,
+    response: TransportResponse,
+) -> Result<Credentials, Error> {
+    if !response.status.is_success() {
+        return Err(Error::invalid_config(format!(
+            "metadata service returned {} for role `{role}`",
+            response.status
+        )));
+    }
+
+    let parsed: RoleCredentials = serde_json::from_str(&response.body).map_err(|source| {
+        Error::invalid_config_with_source(
+            format!("invalid metadata credentials for role `{role}`"),
+            source,
+        )
+    })?;
+
+    if let Some(code) = parsed.code.as_deref().filter(|code| *code != "Success") {
+        return Err(Error::invalid_config(format!(
+            "metadata service returned code `{code}` for role `{role}`"
+        )));
+    }
+
+    Ok(
+        Credentials::new(parsed.tmp_secret_id, parsed.tmp_secret_key)
+            .with_token(parsed.token)
+            .with_expiry(UNIX_EPOCH + Duration::from_secs(parsed.expired_time)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::{HeaderMap, StatusCode};
+
+    fn response(status: StatusCode, body: &str) -> TransportResponse {
+        TransportResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn builds_metadata_urls() {
+        let provider = CvmRoleProvider::new().metadata_base_url("http://127.0.0.1:8080/");
+
+        assert_eq!(
+            provider.url(None).unwrap().as_str(),
+            "http://127.0.0.1:8080/latest/meta-data/cam/security-credentials/"
+        );
+        assert_eq!(
+            provider.url(Some("app-role")).unwrap().as_str(),
+            "http://127.0.0.1:8080/latest/meta-data/cam/security-credentials/app-role"
+        );
+    }
+
+    #[test]
+    fn parses_role_credentials() {
+        let credentials = credentials_from_response(
+            "app-role",
+            response(
+                StatusCode::OK,
+                r#"{
+                    "TmpSecretId": "AKIDtmp",
+                    "TmpSecretKey": "tmp-key",
+                    "ExpiredTime": 1700000000,
+                    "Expiration": "2023-11-14T22:13:20Z",
+                    "Token": "tmp-token",
+                    "Code": "Success"
+                }"#,
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(credentials.secret_id(), "AKIDtmp");
+        assert_eq!(credentials.secret_key(), "tmp-key");
+        assert_eq!(credentials.token(), Some("tmp-token"));
+        assert_eq!(
+            credentials.expires_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+    }
+
+    #[test]
+    fn reports_metadata_failures() {
+        let err =
+            credentials_from_response("missing", response(StatusCode::NOT_FOUND, "")).unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::InvalidConfig);
+        assert_eq!(
+            err.message(),
+            Some("metadata service returned 404 Not Found for role `missing`")
+        );
+
+        let err = role_from_response(response(StatusCode::OK, "\n")).unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some("no CAM role is bound to this CVM instance")
+        );
+        assert_eq!(
+            role_from_response(response(StatusCode::OK, "app-role\n")).unwrap(),
+            "app-role"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use super::{Credentials, CredentialsProvider};
+use crate::{Error, types::Region};
+
+pub const ENV_SECRET_ID: &str = "TENCENTCLOUD_SECRET_ID";
+pub const ENV_SECRET_KEY: &str = "TENCENTCLOUD_SECRET_KEY";
+pub const ENV_SESSION_TOKEN: &str = "TENCENTCLOUD_SESSION_TOKEN";
+pub const ENV_REGION: &str = "TENCENTCLOUD_REGION";
+
+const LEGACY_ENV_SECRET_ID: &str = "TENCENT_SECRET_ID";
+const LEGACY_ENV_SECRET_KEY: &str = "TENCENT_SECRET_KEY";
+const LEGACY_ENV_REGION: &str = "TENCENT_REGION";
+
+/// Reads credentials from `TENCENTCLOUD_SECRET_ID`, `TENCENTCLOUD_SECRET_KEY` and the optional
+/// `TENCENTCLOUD_SESSION_TOKEN`.
+///
+/// The legacy `TENCENT_SECRET_ID` / `TENCENT_SECRET_KEY` pair is used when neither
+/// `TENCENTCLOUD_*` secret is set.
+#[derive(Debug, Clone, Default)]
+pub struct EnvCredentialsProvider {
+    _private: (),
+}
+
+impl EnvCredentialsProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CredentialsProvider for EnvCredentialsProvider {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        credentials_from_lookup(env_var)
+    }
+}
+
+/// Region from `TENCENTCLOUD_REGION`, falling back to the legacy `TENCENT_REGION`.
+pub(crate) fn region_from_env() -> Option<Region> {
+    region_from_lookup(env_var)
+}
+
+/// Whether any credential variable is set, i.e. the environment is meant to supply credentials.
+pub(crate) fn credentials_in_env() -> bool {
+    credential_vars_set(env_var)
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+fn credentials_from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Credentials, Error> {
+    let lookup = |name: &str| {
+        lookup(name)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    let token = lookup(ENV_SESSION_TOKEN);
+    let (secret_id, secret_key) = match (lookup(ENV_SECRET_ID), lookup(ENV_SECRET_KEY)) {
+        (None, None) => match (lookup(LEGACY_ENV_SECRET_ID), lookup(LEGACY_ENV_SECRET_KEY)) {
+            (Some(secret_id), Some(secret_key)) => (secret_id, secret_key),
+            (Some(_), None) => {
+                return Err(partial(LEGACY_ENV_SECRET_ID, LEGACY_ENV_SECRET_KEY));
+            }
+            (None, Some(_)) => {
+                return Err(partial(LEGACY_ENV_SECRET_KEY, LEGACY_ENV_SECRET_ID));
+            }
+            (None, None) if token.is_some() => {
+                return Err(partial(ENV_SESSION_TOKEN, ENV_SECRET_ID));
+            }
+            (None, None) => {
+                return Err(Error::invalid_config(format!(
+                    "no credentials in environment: set {ENV_SECRET_ID} and {ENV_SECRET_KEY}"
+                )));
+            }
+        },
+        (Some(secret_id), Some(secret_key)) => (secret_id, secret_key),
+        (Some(_), None) => return Err(partial(ENV_SECRET_ID, ENV_SECRET_KEY)),
+        (None, Some(_)) => return Err(partial(ENV_SECRET_KEY, ENV_SECRET_ID)),
+    };
+
+    let credentials = Credentials::new(secret_id, secret_key);
+    Ok(match token {
+        Some(token) => credentials.with_token(token),
+        None => credentials,
+    })
+}
+
+fn credential_vars_set(lookup: impl Fn(&str) -> Option<String>) -> bool {
+    [
+        ENV_SECRET_ID,
+        ENV_SECRET_KEY,
+        ENV_SESSION_TOKEN,
+        LEGACY_ENV_SECRET_ID,
+        LEGACY_ENV_SECRET_KEY,
+    ]
+    .into_iter()
+    .filter_map(lookup)
+    .any(|value| !value.trim().is_empty())
+}
+
+fn region_from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Option<Region> {
+    [ENV_REGION, LEGACY_ENV_REGION]
+        .into_iter()
+        .filter_map(&lookup)
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+        .map(Region::from)
+}
+
+fn partial(present: &str, missing: &str) -> Error {
+    Error::invalid_config(format!(
+        "environment variable {present} is set but {missing} is missing"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn reads_tencentcloud_variables_with_token() {
+        let credentials = credentials_from_lookup(lookup(&[
+            (ENV_SECRET_ID, "AKID1"),
+            (ENV_SECRET_KEY, "key1"),
+            (ENV_SESSION_TOKEN, "token1"),
+            (LEGACY_ENV_SECRET_ID, "AKID-legacy"),
+            (LEGACY_ENV_SECRET_KEY, "key-legacy"),
+        ]))
+        .unwrap();
+
+        assert_eq!(credentials.secret_id(), "AKID1");
+        assert_eq!(credentials.secret_key(), "key1");
+        assert_eq!(credentials.token(), Some("token1"));
+    }
+
+    #[test]
+    fn falls_back_to_legacy_variables() {
+        let credentials = credentials_from_lookup(lookup(&[
+            (LEGACY_ENV_SECRET_ID, "AKID-legacy"),
+            (LEGACY_ENV_SECRET_KEY, "key-legacy"),
+        ]))
+        .unwrap();
+
+        assert_eq!(credentials.secret_id(), "AKID-legacy");
+        assert_eq!(credentials.token(), None);
+    }
+
+    #[test]
+    fn rejects_partial_configuration() {
+        let err = credentials_from_lookup(lookup(&[(ENV_SECRET_ID, "AKID1")])).unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::InvalidConfig);
+        assert_eq!(
+            err.message(),
+            Some(
+                "environment variable TENCENTCLOUD_SECRET_ID is set but TENCENTCLOUD_SECRET_KEY is missing"
+            )
+        );
+
+        let err = credentials_from_lookup(lookup(&[(LEGACY_ENV_SECRET_KEY, "key")])).unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some("environment variable TENCENT_SECRET_KEY is set but TENCENT_SECRET_ID is missing")
+        );
+
+        let err = credentials_from_lookup(lookup(&[(ENV_SESSION_TOKEN, "t")])).unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some(
+                "environment variable TENCENTCLOUD_SESSION_TOKEN is set but TENCENTCLOUD_SECRET_ID is missing"
+            )
+        );
+    }
+
+    #[test]
+    fn blank_values_count_as_unset() {
+        let vars = [(ENV_SECRET_ID, " "), (ENV_SECRET_KEY, "")];
+        let err = credentials_from_lookup(lookup(&vars)).unwrap_err();
+        assert!(
+            err.message()
+                .unwrap()
+                .starts_with("no credentials in environment")
+        );
+        assert!(!credential_vars_set(lookup(&vars)));
+        assert!(credential_vars_set(lookup(&[(ENV_SECRET_ID, "AKID1")])));
+    }
+
+    #[test]
+    fn region_prefers_tencentcloud_variable() {
+        let region = region_from_lookup(lookup(&[
+            (ENV_REGION, "ap-shanghai"),
+            (LEGACY_ENV_REGION, "ap-beijing"),
+        ]));
+        assert_eq!(region.as_ref().map(Region::as_str), Some("ap-shanghai"));
+
+        let region = region_from_lookup(lookup(&[(LEGACY_ENV_REGION, "ap-beijing")]));
+        assert_eq!(region.as_ref().map(Region::as_str), Some("ap-beijing"));
+
+        assert!(region_from_lookup(lookup(&[])).is_none());
+    }
+}
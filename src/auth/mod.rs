@@ -0,0 +1,145 @@
+use crate::Error;
+use std::{fmt, sync::Arc};
+
+mod assume_role;
+pub(crate) mod cache;
+mod chain;
+mod cvm_role;
+mod env;
+mod profile;
+mod provider;
+mod web_identity;
+
+pub use assume_role::AssumeRoleProvider;
+pub use chain::{CredentialSource, DefaultCredentialsProvider};
+pub use cvm_role::{CvmRoleProvider, DEFAULT_METADATA_BASE_URL};
+pub use env::{
+    ENV_REGION, ENV_SECRET_ID, ENV_SECRET_KEY, ENV_SESSION_TOKEN, EnvCredentialsProvider,
+};
+pub(crate) use env::{credentials_in_env, region_from_env};
+pub use profile::{ENV_PROFILE, ProfileProvider};
+pub use provider::{Credentials, CredentialsFuture, CredentialsProvider};
+pub use web_identity::{
+    ENV_TKE_PROVIDER_ID, ENV_TKE_REGION, ENV_TKE_ROLE_ARN, ENV_TKE_WEB_IDENTITY_TOKEN_FILE,
+    WebIdentityProvider,
+};
+
+#[derive(Clone)]
+pub enum Auth {
+    Tc3(Tc3Auth),
+    Provider(Arc<dyn CredentialsProvider>),
+    None,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Tc3Auth {
+    secret_id: String,
+    secret_key: String,
+    token: Option<String>,
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::Tc3(auth) => f.debug_tuple("Auth::Tc3").field(auth).finish(),
+            Auth::Provider(_) => f.write_str("Auth::Provider(..)"),
+            Auth::None => f.write_str("Auth::None"),
+        }
+    }
+}
+
+/// Providers compare equal only when they are the same shared instance.
+impl PartialEq for Auth {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Auth::Tc3(left), Auth::Tc3(right)) => left == right,
+            (Auth::Provider(left), Auth::Provider(right)) => Arc::ptr_eq(left, right),
+            (Auth::None, Auth::None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Auth {}
+
+impl fmt::Debug for Tc3Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tc3Auth")
+            .field("secret_id", &"[redacted]")
+            .field("secret_key", &"[redacted]")
+            .field("has_token", &self.token.is_some())
+            .finish()
+    }
+}
+
+impl Auth {
+    pub fn none() -> Self {
+        Self::None
+    }
+
+    pub fn tc3(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self::Tc3(Tc3Auth {
+            secret_id: secret_id.into(),
+            secret_key: secret_key.into(),
+            token: None,
+        })
+    }
+
+    /// Credentials from the first source in [`DefaultCredentialsProvider`]'s chain that works.
+    pub fn default_chain() -> Self {
+        Self::provider(DefaultCredentialsProvider::new())
+    }
+
+    /// Resolves credentials from `provider`, caching them until shortly before they expire.
+    pub fn provider(provider: impl CredentialsProvider + 'static) -> Self {
+        Self::Provider(Arc::new(provider))
+    }
+}
+
+impl Tc3Auth {
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn secret_id(&self) -> &str {
+        &self.secret_id
+    }
+
+    pub(crate) fn secret_key(&self) -> &str {
+        &self.secret_key
+    }
+
+    pub(crate) fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    pub(crate) fn to_credentials(&self) -> Credentials {
+        let credentials = Credentials::new(self.secret_id(), self.secret_key());
+        match self.token() {
+            Some(token) => credentials.with_token(token),
+            None => credentials,
+        }
+    }
+}
+
+impl CredentialsProvider for Tc3Auth {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        Ok(self.to_credentials())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn providers_are_equal_only_to_themselves() {
+        let provider = Auth::provider(Credentials::new("id", "key"));
+
+        assert_eq!(provider, provider.clone());
+        assert_ne!(provider, Auth::provider(Credentials::new("id", "key")));
+        assert_eq!(Auth::tc3("id", "key"), Auth::tc3("id", "key"));
+        assert_ne!(Auth::tc3("id", "key"), Auth::none());
+    }
+}
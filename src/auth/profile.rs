@@ -0,0 +1,275 @@
+use super::{Credentials, CredentialsProvider};
+use crate::{Error, types::Region};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+pub const ENV_PROFILE: &str = "TENCENTCLOUD_PROFILE";
+
+const DEFAULT_PROFILE: &str = "default";
+const TCCLI_DIR: &str = ".tccli";
+const SYS_PARAM: &str = "_sys_param";
+
+/// Reads credentials from a `tccli` profile, i.e. `~/.tccli/<profile>.credential`.
+///
+/// The file is re-read on every lookup, so edits made with `tccli configure` are picked up the
+/// next time the client refreshes credentials.
+#[derive(Debug, Clone)]
+pub struct ProfileProvider {
+    name: String,
+    dir: Option<PathBuf>,
+}
+
+/// Settings from `~/.tccli/<profile>.configure`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProfileConfig {
+    pub(crate) region: Option<Region>,
+    pub(crate) service_endpoints: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct CredentialFile {
+    #[serde(rename = "secretId", default)]
+    secret_id: String,
+    #[serde(rename = "secretKey", default)]
+    secret_key: String,
+    #[serde(default)]
+    token: Option<String>,
+}
+
+impl ProfileProvider {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            dir: None,
+        }
+    }
+
+    /// Uses the profile named by `TENCENTCLOUD_PROFILE`, or `default`.
+    pub fn from_env() -> Self {
+        let name = std::env::var(ENV_PROFILE)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        Self::new(name)
+    }
+
+    /// Reads profile files from `dir` instead of `~/.tccli`.
+    pub fn with_config_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn load_config(&self) -> Result<ProfileConfig, Error> {
+        let path = self.file_path("configure")?;
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(ProfileConfig::default()),
+            Err(err) => return Err(read_error(&path, err)),
+        };
+        let value: Value =
+            serde_json::from_str(&contents).map_err(|err| parse_error(&path, err))?;
+        Ok(config_from_value(&value))
+    }
+
+    fn file_path(&self, extension: &str) -> Result<PathBuf, Error> {
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => home_dir()
+                .ok_or_else(|| {
+                    Error::invalid_config(
+                        "cannot locate tccli profiles: neither HOME nor USERPROFILE is set",
+                    )
+                })?
+                .join(TCCLI_DIR),
+        };
+        Ok(dir.join(format!("{}.{extension}", self.name)))
+    }
+}
+
+impl CredentialsProvider for ProfileProvider {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        let path = self.file_path("credential")?;
+        let contents = fs::read_to_string(&path).map_err(|err| read_error(&path, err))?;
+        let file: CredentialFile =
+            serde_json::from_str(&contents).map_err(|err| parse_error(&path, err))?;
+
+        if file.secret_id.trim().is_empty() || file.secret_key.trim().is_empty() {
+            return Err(Error::invalid_config(format!(
+                "{} does not contain secretId and secretKey",
+                path.display()
+            )));
+        }
+
+        let credentials = Credentials::new(file.secret_id.trim(), file.secret_key.trim());
+        Ok(match file.token.filter(|token| !token.trim().is_empty()) {
+            Some(token) => credentials.with_token(token.trim()),
+            None => credentials,
+        })
+    }
+}
+
+fn config_from_value(value: &Value) -> ProfileConfig {
+    let region = value
+        .get(SYS_PARAM)
+        .and_then(|params| params.get("region"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|region| !region.is_empty())
+        .map(Region::from);
+
+    let service_endpoints = value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(service, _)| service.as_str() != SYS_PARAM)
+        .filter_map(|(service, settings)| {
+            let endpoint = settings.get("endpoint")?.as_str()?;
+            let host = endpoint
+                .trim()
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_end_matches('/');
+            // tccli writes `{service}.tencentcloudapi.com` for every product; importing that
+            // would pin each service and defeat regional and preset endpoints.
+            let is_default = host == format!("{service}.tencentcloudapi.com");
+            (!host.is_empty() && !is_default).then(|| (service.clone(), host.to_string()))
+        })
+        .collect();
+
+    ProfileConfig {
+        region,
+        service_endpoints,
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    ["HOME", "USERPROFILE"]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .find(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+fn read_error(path: &Path, err: std::io::Error) -> Error {
+    Error::invalid_config_with_source(format!("failed to read {}", path.display()), err)
+}
+
+fn parse_error(path: &Path, err: serde_json::Error) -> Error {
+    Error::invalid_config_with_source(format!("failed to parse {}", path.display()), err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn profile_dir(test: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("tencent-sdk-profile-{test}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn reads_credential_file() {
+        let dir = profile_dir("credential");
+        fs::write(
+            dir.join("dev.credential"),
+            r#"{"secretId": "AKIDDEV", "secretKey": "dev-key", "token": "dev-token"}"#,
+        )
+        .unwrap();
+
+        let credentials = ProfileProvider::new("dev")
+            .with_config_dir(&dir)
+            .credentials()
+            .unwrap();
+
+        assert_eq!(credentials.secret_id(), "AKIDDEV");
+        assert_eq!(credentials.secret_key(), "dev-key");
+        assert_eq!(credentials.token(), Some("dev-token"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_missing_or_incomplete_credential_file() {
+        let dir = profile_dir("incomplete");
+        let provider = ProfileProvider::new("absent").with_config_dir(&dir);
+        let err = provider.credentials().unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::InvalidConfig);
+        assert!(err.message().unwrap().contains("absent.credential"));
+
+        fs::write(dir.join("partial.credential"), r#"{"secretId": "AKID"}"#).unwrap();
+        let err = ProfileProvider::new("partial")
+            .with_config_dir(&dir)
+            .credentials()
+            .unwrap_err();
+        assert!(
+            err.message()
+                .unwrap()
+                .ends_with("does not contain secretId and secretKey")
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_configure_file_is_empty_config() {
+        let dir = profile_dir("configure");
+        let config = ProfileProvider::new("dev")
+            .with_config_dir(&dir)
+            .load_config()
+            .unwrap();
+
+        assert!(config.region.is_none());
+        assert!(config.service_endpoints.is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parses_region_and_service_endpoints() {
+        let config = config_from_value(&json!({
+            "_sys_param": {
+                "arrayCount": 10,
+                "output": "json",
+                "region": "ap-shanghai"
+            },
+            "cvm": {
+                "endpoint": "cvm.ap-shanghai.tencentcloudapi.com",
+                "version": "2017-03-12"
+            },
+            "vpc": {
+                "endpoint": "https://vpc.internal.tencentcloudapi.com/"
+            },
+            "tag": {
+                "endpoint": "tag.tencentcloudapi.com"
+            },
+            "cdn": {
+                "version": "2018-06-06"
+            }
+        }));
+
+        assert_eq!(
+            config.region.as_ref().map(Region::as_str),
+            Some("ap-shanghai")
+        );
+        assert_eq!(config.service_endpoints.len(), 2);
+        assert_eq!(
+            config.service_endpoints["cvm"],
+            "cvm.ap-shanghai.tencentcloudapi.com"
+        );
+        assert_eq!(
+            config.service_endpoints["vpc"],
+            "vpc.internal.tencentcloudapi.com"
+        );
+    }
+}
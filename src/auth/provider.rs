@@ -0,0 +1,121 @@
+use crate::Error;
+use std::{
+    fmt,
+    future::{Future, ready},
+    pin::Pin,
+    time::{Duration, SystemTime},
+};
+
+/// Future returned by [`CredentialsProvider::credentials_async`].
+pub type CredentialsFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Credentials, Error>> + Send + 'a>>;
+
+/// A resolved set of TC3 credentials.
+///
+/// Temporary credentials carry a session token and an expiry; the client refreshes them
+/// from their provider shortly before they expire.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Credentials {
+    secret_id: String,
+    secret_key: String,
+    token: Option<String>,
+    expires_at: Option<SystemTime>,
+}
+
+impl Credentials {
+    pub fn new(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            secret_id: secret_id.into(),
+            secret_key: secret_key.into(),
+            token: None,
+            expires_at: None,
+        }
+    }
+
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_expiry(mut self, expires_at: SystemTime) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn secret_id(&self) -> &str {
+        &self.secret_id
+    }
+
+    pub(crate) fn secret_key(&self) -> &str {
+        &self.secret_key
+    }
+
+    pub(crate) fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    pub(crate) fn expires_within(&self, window: Duration) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| SystemTime::now() + window >= expires_at)
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("secret_id", &"[redacted]")
+            .field("secret_key", &"[redacted]")
+            .field("has_token", &self.token.is_some())
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Source of credentials for request signing.
+///
+/// [`BlockingClient`](crate::BlockingClient) calls [`credentials`](Self::credentials) and
+/// [`Client`](crate::Client) calls [`credentials_async`](Self::credentials_async), which falls
+/// back to the blocking lookup unless overridden. Results are cached by the client until they
+/// get close to [`Credentials::expires_at`].
+pub trait CredentialsProvider: Send + Sync {
+    fn credentials(&self) -> Result<Credentials, Error>;
+
+    fn credentials_async(&self) -> CredentialsFuture<'_> {
+        Box::pin(ready(self.credentials()))
+    }
+}
+
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> Result<Credentials, Error> {
+        Ok(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn credentials_debug_redacts_secrets() {
+        let credentials = Credentials::new("AKIDEXAMPLE", "secret").with_token("token");
+        let rendered = format!("{credentials:?}");
+
+        assert!(!rendered.contains("AKIDEXAMPLE"));
+        assert!(!rendered.contains("secret\""));
+        assert!(rendered.contains("has_token: true"));
+    }
+
+    #[test]
+    fn expires_within_ignores_static_credentials() {
+        let credentials = Credentials::new("id", "key");
+        assert!(!credentials.expires_within(Duration::from_secs(3600)));
+
+        let expiring = credentials.with_expiry(SystemTime::now() + Duration::from_secs(60));
+        assert!(expiring.expires_within(Duration::from_secs(300)));
+        assert!(!expiring.expires_within(Duration::from_secs(10)));
+    }
+}
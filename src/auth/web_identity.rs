@@ -0,0 +1,240 @@
+use super::{
+    Auth, Credentials, CredentialsProvider,
+    assume_role::{DEFAULT_STS_BASE_URL, default_session_name},
+};
+use crate::{
+    Error,
+    client::EndpointMode,
+    types::{Region, sts::AssumeRoleWithWebIdentityRequest},
+};
+use std::{fs, path::PathBuf, time::Duration};
+
+#[cfg(feature = "async")]
+use super::CredentialsFuture;
+
+pub const ENV_TKE_PROVIDER_ID: &str = "TKE_PROVIDER_ID";
+pub const ENV_TKE_ROLE_ARN: &str = "TKE_ROLE_ARN";
+pub const ENV_TKE_REGION: &str = "TKE_REGION";
+pub const ENV_TKE_WEB_IDENTITY_TOKEN_FILE: &str = "TKE_WEB_IDENTITY_TOKEN_FILE";
+
+/// Temporary credentials for TKE workloads, obtained with the unsigned
+/// `sts:AssumeRoleWithWebIdentity` call using the pod's projected OIDC token.
+///
+/// The token file is read again on every refresh, since Kubernetes rotates it.
+#[derive(Debug, Clone)]
+pub struct WebIdentityProvider {
+    provider_id: String,
+    role_arn: String,
+    token_file: PathBuf,
+    region: Option<Region>,
+    session_name: String,
+    duration: Option<Duration>,
+    base_url: String,
+    endpoint_mode: EndpointMode,
+}
+
+impl WebIdentityProvider {
+    pub fn new(
+        provider_id: impl Into<String>,
+        role_arn: impl Into<String>,
+        token_file: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            provider_id: provider_id.into(),
+            role_arn: role_arn.into(),
+            token_file: token_file.into(),
+            region: None,
+            session_name: default_session_name(),
+            duration: None,
+            base_url: DEFAULT_STS_BASE_URL.to_string(),
+            endpoint_mode: EndpointMode::ServiceSubdomain,
+        }
+    }
+
+    /// Configures the provider from `TKE_PROVIDER_ID`, `TKE_ROLE_ARN`, `TKE_REGION` and
+    /// `TKE_WEB_IDENTITY_TOKEN_FILE`, as injected into pods by TKE.
+    pub fn from_env() -> Result<Self, Error> {
+        from_lookup(|name| std::env::var(name).ok())
+    }
+
+    pub fn region(mut self, region: impl Into<Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// `RoleSessionName` recorded in CloudAudit (defaults to `tencent-sdk-<unix time>`).
+    pub fn session_name(mut self, session_name: impl Into<String>) -> Self {
+        self.session_name = session_name.into();
+        self
+    }
+
+    /// Lifetime of each set of credentials; whole seconds are sent as `DurationSeconds`.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sends `AssumeRoleWithWebIdentity` to `base_url` instead of `https://tencentcloudapi.com`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn endpoint_mode(mut self, mode: EndpointMode) -> Self {
+        self.endpoint_mode = mode;
+        self
+    }
+
+    fn request(&self) -> Result<AssumeRoleWithWebIdentityRequest, Error> {
+        let token = fs::read_to_string(&self.token_file).map_err(|source| {
+            Error::invalid_config_with_source(
+                format!(
+                    "failed to read web identity token from {}",
+                    self.token_file.display()
+                ),
+                source,
+            )
+        })?;
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(Error::invalid_config(format!(
+                "web identity token file {} is empty",
+                self.token_file.display()
+            )));
+        }
+
+        let mut request = AssumeRoleWithWebIdentityRequest::new(
+            self.provider_id.as_str(),
+            token,
+            self.role_arn.as_str(),
+            self.session_name.as_str(),
+        );
+        if let Some(region) = &self.region {
+            request = request.region(region.clone());
+        }
+        if let Some(duration) = self.duration {
+            request = request.duration_seconds(duration.as_secs());
+        }
+        Ok(request)
+    }
+
+    #[cfg(feature = "blocking")]
+    fn assume_role(&self) -> Result<Credentials, Error> {
+        let request = self.request()?;
+        let client = crate::BlockingClient::builder(&self.base_url)?
+            .endpoint_mode(self.endpoint_mode)
+            .auth(Auth::none())
+            .build()?;
+        let response = client.sts().assume_role_with_web_identity(&request)?;
+        Ok(response.response.to_credentials())
+    }
+
+    #[cfg(feature = "async")]
+    async fn assume_role_async(&self) -> Result<Credentials, Error> {
+        let request = self.request()?;
+        let client = crate::Client::builder(&self.base_url)?
+            .endpoint_mode(self.endpoint_mode)
+            .auth(Auth::none())
+            .build()?;
+        let response = client.sts().assume_role_with_web_identity(&request).await?;
+        Ok(response.response.to_credentials())
+    }
+}
+
+impl CredentialsProvider for WebIdentityProvider {
+    #[cfg(feature = "blocking")]
+    fn credentials(&self) -> Result<Credentials, Error> {
+        self.assume_role()
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    fn credentials(&self) -> Result<Credentials, Error> {
+        Err(Error::invalid_config(
+            "WebIdentityProvider needs the `blocking` feature for synchronous lookups",
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    fn credentials_async(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.assume_role_async())
+    }
+}
+
+fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<WebIdentityProvider, Error> {
+    let lookup = |name: &str| {
+        lookup(name)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let require = |name: &str| {
+        lookup(name)
+            .ok_or_else(|| Error::invalid_config(format!("environment variable {name} is not set")))
+    };
+
+    let provider = WebIdentityProvider::new(
+        require(ENV_TKE_PROVIDER_ID)?,
+        require(ENV_TKE_ROLE_ARN)?,
+        require(ENV_TKE_WEB_IDENTITY_TOKEN_FILE)?,
+    );
+    Ok(match lookup(ENV_TKE_REGION) {
+        Some(region) => provider.region(region),
+        None => provider,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::endpoint::Endpoint;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn reads_tke_environment() {
+        let provider = from_lookup(lookup(&[
+            (ENV_TKE_PROVIDER_ID, "OIDC"),
+            (ENV_TKE_ROLE_ARN, "qcs::cam::uin/100:roleName/pod"),
+            (ENV_TKE_REGION, "ap-guangzhou"),
+            (ENV_TKE_WEB_IDENTITY_TOKEN_FILE, "/var/run/secrets/token"),
+        ]))
+        .unwrap();
+
+        assert_eq!(provider.provider_id, "OIDC");
+        assert_eq!(provider.role_arn, "qcs::cam::uin/100:roleName/pod");
+        assert_eq!(
+            provider.region.as_ref().map(Region::as_str),
+            Some("ap-guangzhou")
+        );
+        assert_eq!(provider.token_file, PathBuf::from("/var/run/secrets/token"));
+
+        let err = from_lookup(lookup(&[(ENV_TKE_PROVIDER_ID, "OIDC")])).unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some("environment variable TKE_ROLE_ARN is not set")
+        );
+    }
+
+    #[test]
+    fn rereads_token_file_for_each_request() {
+        let path =
+            std::env::temp_dir().join(format!("tencent-sdk-web-identity-{}", std::process::id()));
+        let provider = WebIdentityProvider::new("OIDC", "qcs::cam::uin/100:roleName/pod", &path);
+
+        fs::write(&path, "token-1\n").unwrap();
+        let first = provider.request().unwrap().payload().unwrap().unwrap();
+        fs::write(&path, "token-2\n").unwrap();
+        let second = provider.request().unwrap().payload().unwrap().unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(first["WebIdentityToken"], "token-1");
+        assert_eq!(second["WebIdentityToken"], "token-2");
+        assert!(provider.request().is_err());
+    }
+}
@@ -1,23 +1,41 @@
 use crate::{
     Error, Result,
-    auth::Auth,
+    auth::{
+        Auth, CredentialsProvider, EnvCredentialsProvider, ProfileProvider,
+        cache::CredentialsCache, region_from_env,
+    },
     client::{
+        circuit_breaker::{CircuitBreaker, CircuitPermit},
+        clock::{ClockSkew, SIGNATURE_EXPIRE_CODE},
         common::{tencent_error_from_value, tencent_request_id_from_value},
         config::{
             DEFAULT_BODY_SNIPPET_MAX_BYTES, DEFAULT_CONNECT_TIMEOUT, DEFAULT_RETRY_BASE_DELAY,
-            DEFAULT_TIMEOUT, DEFAULT_USER_AGENT, EndpointConfig, EndpointMode, RequestDefaults,
-            RequestOptions, RetryConfig,
+            DEFAULT_TIMEOUT, DEFAULT_USER_AGENT, EndpointConfig, EndpointMode, INTERNAL_BASE_URL,
+            INTL_BASE_URL, RequestDefaults, RequestOptions, TENCENT_CLOUD_BASE_URL,
         },
         endpoint::Endpoint,
+        interceptor::{Interceptor, InterceptorContext, is_signed_header},
+        paginator::{Paginated, Paginator},
+        rate_limit::RateLimiter,
+        raw::{RawEndpoint, RawResponse},
+        response::{Response, ResponseMetadata},
+        retry::{RetryContext, RetryPolicy, StandardRetryPolicy},
+        waiter::Waiter,
     },
     error::request_id_from_headers,
-    signing::{SigningInput, build_tc3_headers},
-    transport::{TransportConfig, blocking_transport::UreqBlockingTransport},
-    types::Region,
-    util::{body_snippet, build_url, canonical_query_string, retry_after_delay, retry_delay},
+    signing::{
+        SignatureMethod, SigningInput, build_tc3_headers, build_unsigned_headers, build_v1_request,
+        content_type_for, flatten_params,
+    },
+    transport::{
+        BlockingTransport, TransportConfig, TransportRequest, TransportResponse,
+        blocking_transport::UreqBlockingTransport,
+    },
+    types::{Region, RequestId},
+    util::{body_snippet, build_url, canonical_query_string, retry_after_delay},
 };
 use chrono::Utc;
-use http::{HeaderValue, Method, StatusCode};
+use http::{HeaderMap, HeaderValue, Method};
 use serde_json::Value;
 use std::{sync::Arc, thread, time::Duration};
 
@@ -27,12 +45,17 @@ pub struct BlockingClient {
 }
 
 struct Inner {
-    auth: Auth,
+    credentials: CredentialsCache,
     endpoint: EndpointConfig,
     default_region: Option<Region>,
-    transport: UreqBlockingTransport,
+    transport: Arc<dyn BlockingTransport>,
     defaults: RequestDefaults,
-    retry: RetryConfig,
+    retry_policy: Arc<dyn RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    signature_method: SignatureMethod,
+    clock: ClockSkew,
 }
 
 pub struct BlockingClientBuilder {
@@ -40,8 +63,15 @@ pub struct BlockingClientBuilder {
     endpoint: EndpointConfig,
     default_region: Option<Region>,
     transport: TransportConfig,
+    custom_transport: Option<Arc<dyn BlockingTransport>>,
+    #[cfg(feature = "testing")]
+    cassette: Option<std::path::PathBuf>,
     defaults: RequestDefaults,
-    retry: RetryConfig,
+    retry_policy: Arc<dyn RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    signature_method: SignatureMethod,
 }
 
 impl BlockingClient {
@@ -50,7 +80,22 @@ impl BlockingClient {
     }
 
     pub fn builder_tencent_cloud() -> Result<BlockingClientBuilder> {
-        BlockingClientBuilder::new("https://tencentcloudapi.com")
+        BlockingClientBuilder::new(TENCENT_CLOUD_BASE_URL)
+    }
+
+    /// Builder for the international site, resolving hosts as `{service}.intl.tencentcloudapi.com`.
+    ///
+    /// Only [`EndpointMode::ServiceSubdomain`] is supported; `build` rejects other modes.
+    pub fn builder_tencent_cloud_intl() -> Result<BlockingClientBuilder> {
+        BlockingClientBuilder::new(INTL_BASE_URL)
+    }
+
+    /// Builder for the private-network endpoints (`{service}.internal.tencentcloudapi.com`),
+    /// reachable from CVM instances and other hosts inside Tencent Cloud VPCs.
+    ///
+    /// Only [`EndpointMode::ServiceSubdomain`] is supported; `build` rejects other modes.
+    pub fn builder_tencent_cloud_internal() -> Result<BlockingClientBuilder> {
+        BlockingClientBuilder::new(INTERNAL_BASE_URL)
     }
 
     pub fn billing(&self) -> crate::api::billing::BlockingBillingService {
@@ -81,11 +126,121 @@ impl BlockingClient {
         crate::api::ssl::BlockingSslService::new(self.clone())
     }
 
-    pub(crate) fn execute<E: Endpoint>(
+    pub fn sts(&self) -> crate::api::sts::BlockingStsService {
+        crate::api::sts::BlockingStsService::new(self.clone())
+    }
+
+    /// Calls an action whose service, version and action names are only known at runtime.
+    ///
+    /// Returns the `Response` object untyped; Tencent Cloud service errors are still surfaced
+    /// as [`Error`] values.
+    pub fn call_raw(
+        &self,
+        service: &str,
+        version: &str,
+        action: &str,
+        region: Option<&str>,
+        payload: Value,
+    ) -> Result<RawResponse> {
+        let endpoint = RawEndpoint::new(service, version, action, region, payload);
+        self.execute(&endpoint, None)
+    }
+
+    pub fn call_raw_with_options(
+        &self,
+        service: &str,
+        version: &str,
+        action: &str,
+        region: Option<&str>,
+        payload: Value,
+        options: &RequestOptions,
+    ) -> Result<RawResponse> {
+        let endpoint = RawEndpoint::new(service, version, action, region, payload);
+        self.execute(&endpoint, Some(options))
+    }
+
+    /// Seconds added to the local clock when signing requests.
+    ///
+    /// Estimated from the server time whenever Tencent Cloud rejects a request with
+    /// `AuthFailure.SignatureExpire`; `0` until that happens.
+    pub fn clock_offset_seconds(&self) -> i64 {
+        self.inner.clock.offset_seconds()
+    }
+
+    /// Pages through an `Offset`/`Limit` list action such as `DescribeInstances`.
+    pub fn paginate<R: Paginated>(&self, request: R) -> Paginator<BlockingClient, R> {
+        Paginator::new(self.clone(), request)
+    }
+
+    /// Re-sends `request` until `waiter` reaches a decision; see [`Waiter`].
+    pub fn wait_until<E: Endpoint>(
+        &self,
+        request: &E,
+        waiter: &Waiter<E::Output>,
+    ) -> Result<Option<E::Output>> {
+        waiter.wait_blocking(|| self.execute(request, None))
+    }
+
+    fn intercept_response(&self, context: &InterceptorContext<'_>, response: &TransportResponse) {
+        for interceptor in &self.inner.interceptors {
+            interceptor.after_response(context, response);
+        }
+    }
+
+    /// Runs every `before_signing` hook and rejects headers that would replace signed ones.
+    fn intercept_headers(&self, context: &InterceptorContext<'_>) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        for interceptor in &self.inner.interceptors {
+            interceptor.before_signing(context, &mut headers)?;
+        }
+        match headers.keys().find(|name| is_signed_header(name)) {
+            Some(name) => Err(Error::invalid_config(format!(
+                "interceptors must not set the signed `{name}` header"
+            ))),
+            None => Ok(headers),
+        }
+    }
+
+    fn intercept_request(
+        &self,
+        context: &InterceptorContext<'_>,
+        request: &mut TransportRequest,
+    ) -> Result<()> {
+        for interceptor in &self.inner.interceptors {
+            interceptor.after_signing(context, request)?;
+        }
+        Ok(())
+    }
+
+    fn intercept_error(&self, context: &InterceptorContext<'_>, err: &Error) {
+        for interceptor in &self.inner.interceptors {
+            interceptor.on_error(context, err);
+        }
+    }
+
+    fn record_circuit(circuit: &mut Option<CircuitPermit<'_>>, error: Option<&Error>) {
+        if let Some(permit) = circuit.take() {
+            permit.record(error);
+        }
+    }
+
+    /// Sends any [`Endpoint`], including request types defined outside this crate.
+    pub fn execute<E: Endpoint>(
         &self,
         endpoint: &E,
         options: Option<&RequestOptions>,
     ) -> Result<E::Output> {
+        self.execute_with_metadata(endpoint, options)
+            .map(Response::into_output)
+    }
+
+    /// Like [`execute`](Self::execute), also returning the request id, status, headers, attempt
+    /// count, latency, host and region of the response.
+    pub fn execute_with_metadata<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        options: Option<&RequestOptions>,
+    ) -> Result<Response<E::Output>> {
         let method = endpoint.method();
         let service = endpoint.service();
         let action = endpoint.action();
@@ -100,7 +255,7 @@ impl BlockingClient {
 
         let query_params = endpoint.query();
         let canonical_query = canonical_query_string(&query_params);
-        let host = self.inner.endpoint.authority_for_service(service);
+        let host = self.inner.endpoint.authority_for_service(service, region);
         let url = build_url(
             &self.inner.endpoint.scheme,
             &host,
@@ -115,14 +270,25 @@ impl BlockingClient {
             .map(serde_json::to_string)
             .transpose()
             .map_err(|source| {
-                Error::invalid_request_with_source(
+                Error::invalid_config_with_source(
                     "failed to serialize request payload",
                     Box::new(source),
                 )
             })?;
 
         let body = body_for_method(&method, payload_string.clone());
-        let signing_payload = body.as_deref().unwrap_or("");
+        let signing_payload = body.as_deref().unwrap_or("").as_bytes();
+
+        let signature_method = endpoint
+            .signature_method()
+            .unwrap_or(self.inner.signature_method);
+        let legacy_params = if signature_method.is_legacy() {
+            let mut params = query_params.clone();
+            params.extend(flatten_params(payload_value.as_ref())?);
+            params
+        } else {
+            Vec::new()
+        };
 
         let timeout = options
             .and_then(|o| o.timeout)
@@ -132,8 +298,10 @@ impl BlockingClient {
             .unwrap_or(self.inner.defaults.capture_body_snippet);
         let idempotency_key = options.and_then(|o| o.idempotency_key.as_ref());
         let retryable_request = endpoint.is_idempotent() || idempotency_key.is_some();
+        let retry_policy: &dyn RetryPolicy = options
+            .and_then(|o| o.retry_policy.as_deref())
+            .unwrap_or(self.inner.retry_policy.as_ref());
 
-        #[cfg(any(feature = "tracing", feature = "metrics"))]
         let started = std::time::Instant::now();
 
         #[cfg(feature = "tracing")]
@@ -149,55 +317,158 @@ impl BlockingClient {
         );
 
         let mut attempt = 0usize;
+        // Retries counted against the retry policy; a clock-skew resend is an attempt, not a retry.
+        let mut retries = 0usize;
+        let mut clock_corrected = false;
         loop {
-            let timestamp = Utc::now().timestamp();
+            let context = InterceptorContext::new(
+                service,
+                action,
+                region,
+                attempt + 1,
+                endpoint.is_idempotent(),
+                started,
+            );
+
+            // Checked first: a rejected attempt takes no rate-limit token and is never signed.
+            let mut circuit = match self
+                .inner
+                .circuit_breaker
+                .as_deref()
+                .map(|breaker| breaker.acquire(&host))
+                .transpose()
+            {
+                Ok(circuit) => circuit,
+                Err(retry_after) => {
+                    let err = Error::circuit_open(host.clone(), retry_after);
+
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(
+                        parent: &span,
+                        retry_after = ?retry_after,
+                        "request rejected by open circuit"
+                    );
+
+                    #[cfg(feature = "metrics")]
+                    super::metrics::record_error(service, action, &err, attempt, started.elapsed());
+
+                    self.intercept_error(&context, &err);
+                    return Err(err);
+                }
+            };
+
+            if let Some(limiter) = &self.inner.rate_limiter {
+                let wait = limiter.reserve(service, action, region);
+                if !wait.is_zero() {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(parent: &span, wait = ?wait, "waiting for rate limiter");
+                    thread::sleep(wait);
+                }
+                #[cfg(feature = "metrics")]
+                super::metrics::record_rate_limit_wait(service, action, wait);
+            }
+
+            let clock_offset = self.inner.clock.offset_seconds();
+            let timestamp = Utc::now().timestamp() + clock_offset;
 
             #[cfg(feature = "tracing")]
             tracing::debug!(parent: &span, attempt = attempt + 1, "sending request");
-            let mut headers = build_tc3_headers(
-                &self.inner.auth,
-                &SigningInput {
-                    method: &method,
-                    service,
-                    host: &host,
-                    path: &path,
-                    canonical_query: &canonical_query,
-                    region,
-                    action,
-                    version,
-                    payload: signing_payload,
-                    timestamp,
-                },
-            )?;
+            let abort = |err: Error| {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    parent: &span,
+                    kind = ?err.kind(),
+                    "request failed before sending"
+                );
+
+                #[cfg(feature = "metrics")]
+                super::metrics::record_error(service, action, &err, attempt, started.elapsed());
 
-            headers.extend(endpoint.extra_headers()?);
+                self.intercept_error(&context, &err);
+                err
+            };
+            let intercepted_headers = self.intercept_headers(&context).map_err(abort)?;
+
+            let signing_input = SigningInput {
+                method: &method,
+                service,
+                host: &host,
+                path: &path,
+                canonical_query: &canonical_query,
+                region,
+                action,
+                version,
+                payload: signing_payload,
+                content_type: content_type_for(&method),
+                timestamp,
+            };
+            let mut request_url = url.clone();
+            let mut request_body = body.clone();
+            let mut headers = if !endpoint.requires_signing() {
+                build_unsigned_headers(&signing_input).map_err(abort)?
+            } else if signature_method.is_legacy() {
+                let credentials = self.inner.credentials.credentials().map_err(abort)?;
+                let (headers, params) = build_v1_request(
+                    &credentials,
+                    &signing_input,
+                    &legacy_params,
+                    signature_method,
+                    fastrand::u32(1..),
+                )
+                .map_err(abort)?;
+                if method == Method::GET || method == Method::HEAD {
+                    request_url.set_query(Some(&params));
+                    request_body = None;
+                } else {
+                    request_url.set_query(None);
+                    request_body = Some(params);
+                }
+                headers
+            } else {
+                let credentials = self.inner.credentials.credentials().map_err(abort)?;
+                build_tc3_headers(&credentials, &signing_input).map_err(abort)?
+            };
+
+            headers.extend(endpoint.extra_headers().map_err(abort)?);
+            headers.extend(intercepted_headers);
 
             if let Some(key) = idempotency_key {
                 headers.insert(
                     "Idempotency-Key",
                     HeaderValue::from_str(key.as_str()).map_err(|source| {
-                        Error::invalid_request_with_source("invalid idempotency key", source)
+                        abort(Error::invalid_config_with_source(
+                            "invalid idempotency key",
+                            source,
+                        ))
                     })?,
                 );
             }
 
-            let response = self.inner.transport.send(
-                method.clone(),
-                url.clone(),
-                headers,
-                body.clone(),
-                timeout,
-            );
+            let mut request =
+                TransportRequest::new(method.clone(), request_url, headers, request_body, timeout);
+            self.intercept_request(&context, &mut request)
+                .map_err(abort)?;
+
+            let response = self.inner.transport.send(request);
 
             let response = match response {
-                Ok(value) => value,
+                Ok(value) => {
+                    self.intercept_response(&context, &value);
+                    value
+                }
                 Err(err) => {
-                    if attempt < self.inner.retry.max_retries
-                        && retryable_request
-                        && err.is_retryable()
-                    {
+                    Self::record_circuit(&mut circuit, Some(&err));
+                    self.intercept_error(&context, &err);
+                    if let Some(delay) = retry_policy.retry_delay(&RetryContext::new(
+                        retries + 1,
+                        &err,
+                        None,
+                        retryable_request,
+                        service,
+                        action,
+                    )) {
                         attempt += 1;
-                        let delay = retry_delay(self.inner.retry.base_delay, attempt);
+                        retries += 1;
 
                         #[cfg(feature = "tracing")]
                         tracing::warn!(
@@ -228,29 +499,6 @@ impl BlockingClient {
             };
 
             if !response.status.is_success() {
-                if attempt < self.inner.retry.max_retries
-                    && retryable_request
-                    && is_retryable_status(response.status)
-                {
-                    attempt += 1;
-                    let delay = retry_after_delay(&response.headers)
-                        .unwrap_or_else(|| retry_delay(self.inner.retry.base_delay, attempt));
-
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!(
-                        parent: &span,
-                        attempt,
-                        status = %response.status,
-                        delay = ?delay,
-                        "retrying after retryable HTTP status"
-                    );
-
-                    if !delay.is_zero() {
-                        thread::sleep(delay);
-                    }
-                    continue;
-                }
-
                 let request_id_header = request_id_from_headers(&response.headers);
                 let retry_after = retry_after_delay(&response.headers);
                 let (code, message, request_id_body) =
@@ -267,15 +515,43 @@ impl BlockingClient {
 
                 let err = Error::api(
                     Some(response.status),
-                    method,
-                    host,
-                    path,
+                    method.clone(),
+                    host.clone(),
+                    path.clone(),
                     code,
                     message,
                     request_id,
                     snippet,
                     retry_after,
                 );
+                Self::record_circuit(&mut circuit, Some(&err));
+                self.intercept_error(&context, &err);
+
+                if let Some(delay) = retry_policy.retry_delay(&RetryContext::new(
+                    retries + 1,
+                    &err,
+                    retry_after,
+                    retryable_request,
+                    service,
+                    action,
+                )) {
+                    attempt += 1;
+                    retries += 1;
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        parent: &span,
+                        attempt,
+                        status = %response.status,
+                        delay = ?delay,
+                        "retrying after retryable HTTP status"
+                    );
+
+                    if !delay.is_zero() {
+                        thread::sleep(delay);
+                    }
+                    continue;
+                }
 
                 #[cfg(feature = "tracing")]
                 tracing::error!(
@@ -306,6 +582,8 @@ impl BlockingClient {
                     snippet,
                     Box::new(source),
                 );
+                Self::record_circuit(&mut circuit, Some(&err));
+                self.intercept_error(&context, &err);
 
                 #[cfg(feature = "tracing")]
                 tracing::error!(
@@ -339,13 +617,43 @@ impl BlockingClient {
                     snippet,
                     retry_after_delay(&response.headers),
                 );
-
-                if attempt < self.inner.retry.max_retries && retryable_request && err.is_retryable()
+                Self::record_circuit(&mut circuit, Some(&err));
+                self.intercept_error(&context, &err);
+
+                // Rejected before processing, so resending with a corrected timestamp is safe.
+                if !clock_corrected
+                    && endpoint.requires_signing()
+                    && err.code() == Some(SIGNATURE_EXPIRE_CODE)
+                    && self
+                        .inner
+                        .clock
+                        .correct(&response.headers, err.message())
+                        .is_some_and(|offset| offset != clock_offset)
                 {
+                    clock_corrected = true;
                     attempt += 1;
-                    let delay = err
-                        .retry_after()
-                        .unwrap_or_else(|| retry_delay(self.inner.retry.base_delay, attempt));
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        parent: &span,
+                        attempt,
+                        offset_seconds = self.inner.clock.offset_seconds(),
+                        "retrying after correcting clock skew"
+                    );
+
+                    continue;
+                }
+
+                if let Some(delay) = retry_policy.retry_delay(&RetryContext::new(
+                    retries + 1,
+                    &err,
+                    err.retry_after(),
+                    retryable_request,
+                    service,
+                    action,
+                )) {
+                    attempt += 1;
+                    retries += 1;
 
                     #[cfg(feature = "tracing")]
                     tracing::warn!(
@@ -379,6 +687,8 @@ impl BlockingClient {
                 return Err(err);
             }
 
+            Self::record_circuit(&mut circuit, None);
+
             let request_id = tencent_request_id_from_value(&json)
                 .or_else(|| request_id_from_headers(&response.headers));
             let snippet = capture_body_snippet
@@ -394,6 +704,7 @@ impl BlockingClient {
                     snippet.clone(),
                     Box::new(source),
                 );
+                self.intercept_error(&context, &err);
 
                 #[cfg(feature = "tracing")]
                 tracing::error!(
@@ -429,7 +740,17 @@ impl BlockingClient {
                 started.elapsed(),
             );
 
-            return Ok(output);
+            retry_policy.on_success();
+            let metadata = ResponseMetadata::new(
+                request_id.map(RequestId::new),
+                response.status,
+                response.headers,
+                attempt + 1,
+                started.elapsed(),
+                host,
+                region_owned,
+            );
+            return Ok(Response::new(output, metadata));
         }
     }
 }
@@ -449,15 +770,19 @@ impl BlockingClientBuilder {
                 connect_timeout: DEFAULT_CONNECT_TIMEOUT,
                 read_timeout: None,
             },
+            custom_transport: None,
+            #[cfg(feature = "testing")]
+            cassette: None,
             defaults: RequestDefaults {
                 timeout: DEFAULT_TIMEOUT,
                 capture_body_snippet: true,
                 body_snippet_max_bytes: DEFAULT_BODY_SNIPPET_MAX_BYTES,
             },
-            retry: RetryConfig {
-                max_retries: 0,
-                base_delay: DEFAULT_RETRY_BASE_DELAY,
-            },
+            retry_policy: Arc::new(StandardRetryPolicy::new(0, DEFAULT_RETRY_BASE_DELAY)),
+            rate_limiter: None,
+            circuit_breaker: None,
+            interceptors: Vec::new(),
+            signature_method: SignatureMethod::default(),
         })
     }
 
@@ -466,11 +791,70 @@ impl BlockingClientBuilder {
         self
     }
 
+    /// Uses [`EnvCredentialsProvider`] for credentials and `TENCENTCLOUD_REGION` (when set) as the
+    /// default region.
+    ///
+    /// Fails with an `InvalidConfig` error when the environment holds no or only partial credentials.
+    pub fn load_env(mut self) -> Result<Self> {
+        let provider = EnvCredentialsProvider::new();
+        provider.credentials()?;
+        self.auth = Auth::provider(provider);
+        if let Some(region) = region_from_env() {
+            self.default_region = Some(region);
+        }
+        Ok(self)
+    }
+
+    /// Builder for Tencent Cloud configured from the `tccli` profile `name`.
+    ///
+    /// See [`load_profile`](Self::load_profile).
+    pub fn from_profile(name: impl Into<String>) -> Result<Self> {
+        BlockingClient::builder_tencent_cloud()?.load_profile(ProfileProvider::new(name))
+    }
+
+    /// Like [`from_profile`](Self::from_profile), using the profile named by
+    /// `TENCENTCLOUD_PROFILE` (or `default`).
+    pub fn from_default_profile() -> Result<Self> {
+        BlockingClient::builder_tencent_cloud()?.load_profile(ProfileProvider::from_env())
+    }
+
+    /// Takes credentials from `profile` and, when its `.configure` file sets them, the default
+    /// region and per-service endpoint hosts.
+    ///
+    /// Profile endpoints only apply while the builder targets `https://tencentcloudapi.com` in
+    /// [`EndpointMode::ServiceSubdomain`]; a custom base URL or port, another mode, a preset or
+    /// [`service_endpoint`](Self::service_endpoint) takes precedence.
+    pub fn load_profile(mut self, profile: ProfileProvider) -> Result<Self> {
+        profile.credentials()?;
+        let config = profile.load_config()?;
+        self.auth = Auth::provider(profile);
+        if let Some(region) = config.region {
+            self.default_region = Some(region);
+        }
+        self.endpoint.profile_hosts = config.service_endpoints;
+        Ok(self)
+    }
+
     pub fn endpoint_mode(mut self, mode: EndpointMode) -> Self {
         self.endpoint.mode = mode;
         self
     }
 
+    /// Pins `service` (e.g. `cvm`) to `host`, such as a private or regional endpoint, in every
+    /// [`EndpointMode`] but `FixedHost`. Requests are signed for that host.
+    pub fn service_endpoint(mut self, service: impl Into<String>, host: impl Into<String>) -> Self {
+        self.endpoint
+            .service_hosts
+            .insert(service.into(), host.into());
+        self
+    }
+
+    /// Signature method for endpoints that do not choose their own (TC3 by default).
+    pub fn signature_method(mut self, method: SignatureMethod) -> Self {
+        self.signature_method = method;
+        self
+    }
+
     pub fn default_region(mut self, region: impl Into<Region>) -> Self {
         self.default_region = Some(region.into());
         self
@@ -516,23 +900,90 @@ impl BlockingClientBuilder {
         self
     }
 
-    pub fn retry(mut self, max_retries: usize, base_delay: Duration) -> Self {
-        self.retry.max_retries = max_retries;
-        self.retry.base_delay = base_delay;
+    /// Sends requests through `transport` instead of the built-in ureq client.
+    ///
+    /// `user_agent`, `connect_timeout`, `read_timeout`, `danger_accept_invalid_certs` and
+    /// `no_system_proxy` only configure the built-in client and have no effect on `transport`.
+    pub fn transport(mut self, transport: impl BlockingTransport + 'static) -> Self {
+        self.custom_transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Records every exchange to the JSON cassette at `path`, overwriting it.
+    ///
+    /// Request and response bodies are stored with credentials, tokens and signatures
+    /// redacted. Replay the file with [`replay_cassette`](Self::replay_cassette).
+    #[cfg(feature = "testing")]
+    pub fn record_cassette(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.cassette = Some(path.into());
+        self
+    }
+
+    /// Answers requests from a cassette written by [`record_cassette`](Self::record_cassette)
+    /// instead of the network; see [`CassettePlayer`](crate::testing::CassettePlayer).
+    #[cfg(feature = "testing")]
+    pub fn replay_cassette(self, path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Ok(self.transport(crate::testing::CassettePlayer::open(path)?))
+    }
+
+    /// Shorthand for a [`StandardRetryPolicy`] with `max_retries` and `base_delay`.
+    pub fn retry(self, max_retries: usize, base_delay: Duration) -> Self {
+        self.retry_policy(StandardRetryPolicy::new(max_retries, base_delay))
+    }
+
+    /// Retry policy for every request; [`RequestOptions::retry_policy`] overrides it per request.
+    pub fn retry_policy(mut self, policy: impl RetryPolicy + 'static) -> Self {
+        self.retry_policy = Arc::new(policy);
+        self
+    }
+
+    /// Queues requests on client-side token buckets before they are sent; see [`RateLimiter`].
+    ///
+    /// Pass an `Arc<RateLimiter>` to share the buckets between clients.
+    pub fn rate_limiter(mut self, limiter: impl Into<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = Some(limiter.into());
+        self
+    }
+
+    /// Fails requests fast while their endpoint host keeps failing; see [`CircuitBreaker`].
+    ///
+    /// Pass an `Arc<CircuitBreaker>` to share the circuits between clients.
+    pub fn circuit_breaker(mut self, breaker: impl Into<Arc<CircuitBreaker>>) -> Self {
+        self.circuit_breaker = Some(breaker.into());
+        self
+    }
+
+    /// Adds an [`Interceptor`] called around every attempt, after the ones added before it.
+    pub fn interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
         self
     }
 
     pub fn build(self) -> Result<BlockingClient> {
-        let transport = UreqBlockingTransport::new(&self.transport)?;
+        self.endpoint.validate()?;
+        let transport: Arc<dyn BlockingTransport> = match self.custom_transport {
+            Some(transport) => transport,
+            None => Arc::new(UreqBlockingTransport::new(&self.transport)?),
+        };
+        #[cfg(feature = "testing")]
+        let transport: Arc<dyn BlockingTransport> = match self.cassette {
+            Some(path) => Arc::new(crate::testing::CassetteRecorder::new(transport, path)),
+            None => transport,
+        };
 
         Ok(BlockingClient {
             inner: Arc::new(Inner {
-                auth: self.auth,
+                credentials: CredentialsCache::new(self.auth),
                 endpoint: self.endpoint,
                 default_region: self.default_region,
                 transport,
                 defaults: self.defaults,
-                retry: self.retry,
+                retry_policy: self.retry_policy,
+                rate_limiter: self.rate_limiter,
+                circuit_breaker: self.circuit_breaker,
+                interceptors: self.interceptors,
+                signature_method: self.signature_method,
+                clock: ClockSkew::default(),
             }),
         })
     }
@@ -545,13 +996,3 @@ fn body_for_method(method: &Method, payload: Option<String>) -> Option<String> {
         payload
     }
 }
-
-fn is_retryable_status(status: StatusCode) -> bool {
-    matches!(
-        status,
-        StatusCode::TOO_MANY_REQUESTS
-            | StatusCode::BAD_GATEWAY
-            | StatusCode::SERVICE_UNAVAILABLE
-            | StatusCode::GATEWAY_TIMEOUT
-    )
-}
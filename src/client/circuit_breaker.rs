@@ -0,0 +1,397 @@
+use crate::{Error, error::ErrorKind};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// State of the circuit for one endpoint host.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests fail fast with [`ErrorKind::CircuitOpen`] until the cooldown ends.
+    Open,
+    /// A single probe request is allowed through; its outcome closes or reopens the circuit.
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// Label used in logs and metrics, e.g. `half_open`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Fails requests fast while an endpoint host keeps failing.
+///
+/// Each host, e.g. `cvm.ap-guangzhou.tencentcloudapi.com`, has its own circuit. It opens after
+/// [`failure_threshold`](Self::failure_threshold) consecutive attempts fail with a transport
+/// error, an HTTP 5xx, a body that is not JSON or an `InternalError` code; other responses reset
+/// the count. While open, requests to the host return [`ErrorKind::CircuitOpen`] without being
+/// sent. After the [`cooldown`](Self::cooldown) one probe is let through: success closes the
+/// circuit, failure opens it again.
+///
+/// State changes are logged with the `tracing` feature and recorded as
+/// `tencent_sdk_circuit_breaker_transitions_total` with the `metrics` feature.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Circuit {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        since: Instant,
+    },
+    /// `probe_started` is `None` while no probe is in flight.
+    HalfOpen {
+        probe_started: Option<Instant>,
+    },
+}
+
+impl Circuit {
+    fn state(&self) -> CircuitState {
+        match self {
+            Circuit::Closed { .. } => CircuitState::Closed,
+            Circuit::Open { .. } => CircuitState::Open,
+            Circuit::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CircuitBreaker {
+    /// Opens after 5 consecutive failures and probes again after 30 seconds.
+    pub fn new() -> Self {
+        Self {
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            cooldown: DEFAULT_COOLDOWN,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Consecutive failed attempts that open the circuit. Zero is treated as one.
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// How long the circuit stays open before a probe is let through.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Current state of the circuit for `host`.
+    pub fn state(&self, host: &str) -> CircuitState {
+        self.lock()
+            .get(host)
+            .map_or(CircuitState::Closed, Circuit::state)
+    }
+
+    /// Admits an attempt to `host`, or returns how long the circuit stays open.
+    ///
+    /// Dropping the permit without recording an outcome, e.g. when the attempt fails before it is
+    /// sent or its future is dropped, frees the probe slot for the next request.
+    pub(crate) fn acquire(&self, host: &str) -> std::result::Result<CircuitPermit<'_>, Duration> {
+        let probe = self.acquire_at(host, Instant::now())?;
+        Ok(CircuitPermit {
+            breaker: self,
+            host: host.to_string(),
+            probe,
+        })
+    }
+
+    /// Returns the start of the probe when the attempt is one.
+    fn acquire_at(
+        &self,
+        host: &str,
+        now: Instant,
+    ) -> std::result::Result<Option<Instant>, Duration> {
+        let mut circuits = self.lock();
+        let Some(circuit) = circuits.get_mut(host) else {
+            return Ok(None);
+        };
+        let since = match *circuit {
+            Circuit::Closed { .. } => return Ok(None),
+            Circuit::HalfOpen {
+                probe_started: None,
+            } => None,
+            Circuit::Open { since }
+            | Circuit::HalfOpen {
+                probe_started: Some(since),
+            } => Some(since),
+        };
+
+        if let Some(since) = since {
+            let elapsed = now.saturating_duration_since(since);
+            if elapsed < self.cooldown {
+                return Err(self.cooldown - elapsed);
+            }
+        }
+        let from = circuit.state();
+        *circuit = Circuit::HalfOpen {
+            probe_started: Some(now),
+        };
+        drop(circuits);
+        if from != CircuitState::HalfOpen {
+            transition(host, from, CircuitState::HalfOpen);
+        }
+        Ok(Some(now))
+    }
+
+    /// Frees the probe slot taken at `probe_started` unless another outcome replaced it.
+    fn release(&self, host: &str, probe_started: Instant) {
+        let mut circuits = self.lock();
+        if let Some(circuit) = circuits.get_mut(host)
+            && let Circuit::HalfOpen {
+                probe_started: Some(started),
+            } = *circuit
+            && started == probe_started
+        {
+            *circuit = Circuit::HalfOpen {
+                probe_started: None,
+            };
+        }
+    }
+
+    fn record(&self, host: &str, error: Option<&Error>) {
+        self.record_at(host, error.is_some_and(is_circuit_failure), Instant::now());
+    }
+
+    fn record_at(&self, host: &str, failed: bool, now: Instant) {
+        let mut circuits = self.lock();
+        let circuit = circuits
+            .entry(host.to_string())
+            .or_insert(Circuit::Closed { failures: 0 });
+        let from = circuit.state();
+        *circuit = match (*circuit, failed) {
+            // A late success from before the circuit opened does not skip the cooldown and probe.
+            (Circuit::Open { since }, false) => Circuit::Open { since },
+            (_, false) => Circuit::Closed { failures: 0 },
+            (Circuit::Closed { failures }, true) if failures + 1 < self.failure_threshold => {
+                Circuit::Closed {
+                    failures: failures + 1,
+                }
+            }
+            // A late failure from before the circuit opened does not extend the cooldown.
+            (Circuit::Open { since }, true) => Circuit::Open { since },
+            (_, true) => Circuit::Open { since: now },
+        };
+        let to = circuit.state();
+        drop(circuits);
+        if from != to {
+            transition(host, from, to);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Circuit>> {
+        self.circuits.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// An attempt admitted by [`CircuitBreaker::acquire`].
+pub(crate) struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    host: String,
+    probe: Option<Instant>,
+}
+
+impl CircuitPermit<'_> {
+    /// Records the outcome of the attempt; `None` means the host answered normally.
+    pub(crate) fn record(mut self, error: Option<&Error>) {
+        self.probe = None;
+        self.breaker.record(&self.host, error);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if let Some(probe_started) = self.probe {
+            self.breaker.release(&self.host, probe_started);
+        }
+    }
+}
+
+/// Transport errors, HTTP 5xx, unparseable bodies and `InternalError` codes count against the
+/// circuit.
+fn is_circuit_failure(err: &Error) -> bool {
+    matches!(err.kind(), ErrorKind::Transport | ErrorKind::Decode)
+        || err.status().is_some_and(|status| status.is_server_error())
+        || err
+            .code()
+            .is_some_and(|code| code == "InternalError" || code.starts_with("InternalError."))
+}
+
+#[cfg_attr(
+    not(any(feature = "tracing", feature = "metrics")),
+    allow(unused_variables)
+)]
+fn transition(host: &str, from: CircuitState, to: CircuitState) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        host,
+        from = from.as_str(),
+        to = to.as_str(),
+        "circuit breaker state changed"
+    );
+
+    #[cfg(feature = "metrics")]
+    super::metrics::record_circuit_transition(host, to);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_consecutive_failures_and_fails_fast() {
+        let breaker = CircuitBreaker::new()
+            .failure_threshold(2)
+            .cooldown(Duration::from_secs(10));
+        let now = Instant::now();
+
+        breaker.record_at("cvm", true, now);
+        breaker.record_at("cvm", false, now);
+        breaker.record_at("cvm", true, now);
+        assert_eq!(breaker.state("cvm"), CircuitState::Closed);
+
+        breaker.record_at("cvm", true, now);
+        assert_eq!(breaker.state("cvm"), CircuitState::Open);
+        assert_eq!(
+            breaker.acquire_at("cvm", now + Duration::from_secs(4)),
+            Err(Duration::from_secs(6))
+        );
+        assert_eq!(breaker.acquire_at("vpc", now), Ok(None));
+    }
+
+    #[test]
+    fn half_open_probe_closes_or_reopens() {
+        let breaker = CircuitBreaker::new()
+            .failure_threshold(1)
+            .cooldown(Duration::from_secs(10));
+        let now = Instant::now();
+        breaker.record_at("cvm", true, now);
+
+        let probe = now + Duration::from_secs(10);
+        assert_eq!(breaker.acquire_at("cvm", probe), Ok(Some(probe)));
+        assert_eq!(breaker.state("cvm"), CircuitState::HalfOpen);
+        assert_eq!(
+            breaker.acquire_at("cvm", probe + Duration::from_secs(1)),
+            Err(Duration::from_secs(9))
+        );
+
+        breaker.record_at("cvm", true, probe);
+        assert_eq!(breaker.state("cvm"), CircuitState::Open);
+
+        let probe = probe + Duration::from_secs(10);
+        assert_eq!(breaker.acquire_at("cvm", probe), Ok(Some(probe)));
+        breaker.record_at("cvm", false, probe);
+        assert_eq!(breaker.state("cvm"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn unrecorded_probe_frees_its_slot() {
+        let breaker = CircuitBreaker::new()
+            .failure_threshold(1)
+            .cooldown(Duration::from_secs(10));
+        let now = Instant::now();
+        breaker.record_at("cvm", true, now);
+
+        let probe = now + Duration::from_secs(10);
+        let permit = CircuitPermit {
+            breaker: &breaker,
+            host: "cvm".to_string(),
+            probe: breaker.acquire_at("cvm", probe).unwrap(),
+        };
+        assert!(breaker.acquire_at("cvm", probe).is_err());
+        drop(permit);
+        assert_eq!(breaker.state("cvm"), CircuitState::HalfOpen);
+
+        let retry = probe + Duration::from_secs(1);
+        assert_eq!(breaker.acquire_at("cvm", retry), Ok(Some(retry)));
+        // A stale release does not free the newer probe's slot.
+        breaker.release("cvm", probe);
+        assert!(breaker.acquire_at("cvm", retry).is_err());
+    }
+
+    #[test]
+    fn late_success_keeps_the_circuit_open() {
+        let breaker = CircuitBreaker::new()
+            .failure_threshold(1)
+            .cooldown(Duration::from_secs(10));
+        let now = Instant::now();
+        breaker.record_at("cvm", true, now);
+
+        breaker.record_at("cvm", false, now + Duration::from_secs(1));
+        assert_eq!(breaker.state("cvm"), CircuitState::Open);
+        assert_eq!(
+            breaker.acquire_at("cvm", now + Duration::from_secs(2)),
+            Err(Duration::from_secs(8))
+        );
+    }
+
+    #[test]
+    fn only_server_side_failures_count() {
+        use http::{Method, StatusCode};
+
+        let api_error = |status, code: &str| {
+            Error::api(
+                Some(status),
+                Method::POST,
+                "cvm.tencentcloudapi.com",
+                "/",
+                Some(code.to_string()),
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+
+        assert!(is_circuit_failure(&api_error(
+            StatusCode::OK,
+            "InternalError.DbError"
+        )));
+        assert!(is_circuit_failure(&api_error(
+            StatusCode::BAD_GATEWAY,
+            "Unknown"
+        )));
+        assert!(!is_circuit_failure(&api_error(
+            StatusCode::OK,
+            "InvalidParameter"
+        )));
+        assert!(!is_circuit_failure(&api_error(
+            StatusCode::OK,
+            "RequestLimitExceeded"
+        )));
+        assert!(is_circuit_failure(&Error::decode(
+            Some(StatusCode::OK),
+            Method::POST,
+            "cvm.tencentcloudapi.com",
+            "/",
+            None,
+            None,
+            Box::new(serde_json::from_str::<serde_json::Value>("<html>").unwrap_err()),
+        )));
+    }
+}
@@ -0,0 +1,95 @@
+use chrono::Utc;
+use http::HeaderMap;
+use std::{
+    sync::atomic::{AtomicI64, Ordering},
+    time::UNIX_EPOCH,
+};
+
+/// Error code returned when `X-TC-Timestamp` is more than five minutes off the server clock.
+pub(crate) const SIGNATURE_EXPIRE_CODE: &str = "AuthFailure.SignatureExpire";
+
+/// Offset between the local clock and Tencent Cloud's, applied to signing timestamps.
+#[derive(Debug, Default)]
+pub(crate) struct ClockSkew {
+    offset_seconds: AtomicI64,
+}
+
+impl ClockSkew {
+    pub(crate) fn offset_seconds(&self) -> i64 {
+        self.offset_seconds.load(Ordering::Relaxed)
+    }
+
+    /// Re-estimates the offset from a `SignatureExpire` response, using the `Date` header or a
+    /// server timestamp in the error message.
+    ///
+    /// Returns the new offset, or `None` when the response carries no server time.
+    pub(crate) fn correct(&self, headers: &HeaderMap, message: Option<&str>) -> Option<i64> {
+        let server_time = server_time_from_headers(headers)
+            .or_else(|| message.and_then(server_time_from_message))?;
+        let offset = server_time - Utc::now().timestamp();
+        self.offset_seconds.store(offset, Ordering::Relaxed);
+        Some(offset)
+    }
+}
+
+fn server_time_from_headers(headers: &HeaderMap) -> Option<i64> {
+    let value = headers.get(http::header::DATE)?.to_str().ok()?;
+    let time = httpdate::parse_http_date(value.trim()).ok()?;
+    let seconds = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(seconds).ok()
+}
+
+/// Picks the Unix timestamp following "server" in messages such as
+/// `... timestamp 1700000000, server time 1700000600`.
+fn server_time_from_message(message: &str) -> Option<i64> {
+    let lower = message.to_ascii_lowercase();
+    let rest = &message[lower.find("server")?..];
+    rest.split(|c: char| !c.is_ascii_digit())
+        .find(|digits| digits.len() == 10)
+        .and_then(|digits| digits.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn corrects_offset_from_date_header() {
+        let skew = ClockSkew::default();
+        let mut headers = HeaderMap::new();
+        let server = SystemTime::now() + Duration::from_secs(600);
+        headers.insert(
+            http::header::DATE,
+            HeaderValue::from_str(&httpdate::fmt_http_date(server)).unwrap(),
+        );
+
+        let offset = skew.correct(&headers, None).unwrap();
+        assert!((599..=601).contains(&offset));
+        assert_eq!(skew.offset_seconds(), offset);
+    }
+
+    #[test]
+    fn falls_back_to_server_time_in_message() {
+        let skew = ClockSkew::default();
+        let server_time = Utc::now().timestamp() - 900;
+        let message = format!("Signature expired. Timestamp: 1, server time: {server_time}");
+
+        let offset = skew.correct(&HeaderMap::new(), Some(&message)).unwrap();
+        assert!((-901..=-899).contains(&offset));
+    }
+
+    #[test]
+    fn ignores_responses_without_server_time() {
+        let skew = ClockSkew::default();
+        assert_eq!(
+            skew.correct(
+                &HeaderMap::new(),
+                Some("The signature expired. Please check the local time.")
+            ),
+            None
+        );
+        assert_eq!(skew.offset_seconds(), 0);
+    }
+}
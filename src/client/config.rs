@@ -1,12 +1,22 @@
-use crate::Error;
-use std::{fmt, time::Duration};
+use crate::{Error, client::RetryPolicy, util::backoff_delay};
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
 use url::Url;
 
+pub(crate) const TENCENT_CLOUD_BASE_URL: &str = "https://tencentcloudapi.com";
+pub(crate) const INTL_BASE_URL: &str = "https://intl.tencentcloudapi.com";
+pub(crate) const INTERNAL_BASE_URL: &str = "https://internal.tencentcloudapi.com";
+const TENCENT_CLOUD_HOST: &str = "tencentcloudapi.com";
+/// Domains that only serve `{service}.{domain}` hosts.
+const SERVICE_SUBDOMAIN_ONLY_HOSTS: [&str; 2] =
+    ["intl.tencentcloudapi.com", "internal.tencentcloudapi.com"];
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!("tencent-sdk/", env!("CARGO_PKG_VERSION"));
 pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 pub(crate) const DEFAULT_BODY_SNIPPET_MAX_BYTES: usize = 4096;
 pub(crate) const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
+pub(crate) const DEFAULT_WAIT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+pub(crate) const DEFAULT_WAIT_MAX_INTERVAL: Duration = Duration::from_secs(30);
+pub(crate) const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[non_exhaustive]
@@ -15,13 +25,18 @@ pub enum EndpointMode {
     ServiceSubdomain,
     /// Always use `base_host` directly (useful for testing against a mock server).
     FixedHost,
+    /// Resolve `service` and the request's region into `{service}.{region}.{base_host}`, e.g.
+    /// `cvm.ap-shanghai.tencentcloudapi.com`. Requests without a region use
+    /// `{service}.{base_host}`. Not available on the international and internal domains.
+    Regional,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct RequestOptions {
     pub(crate) timeout: Option<Duration>,
     pub(crate) capture_body_snippet: Option<bool>,
     pub(crate) idempotency_key: Option<IdempotencyKey>,
+    pub(crate) retry_policy: Option<Arc<dyn RetryPolicy>>,
 }
 
 impl RequestOptions {
@@ -43,6 +58,100 @@ impl RequestOptions {
         self.idempotency_key = Some(key.into());
         self
     }
+
+    /// Replaces the client's retry policy for this request.
+    ///
+    /// Pass an `Arc` to share one policy (and its retry budget) across requests.
+    pub fn retry_policy(mut self, policy: impl RetryPolicy + 'static) -> Self {
+        self.retry_policy = Some(Arc::new(policy));
+        self
+    }
+}
+
+impl fmt::Debug for RequestOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestOptions")
+            .field("timeout", &self.timeout)
+            .field("capture_body_snippet", &self.capture_body_snippet)
+            .field("idempotency_key", &self.idempotency_key)
+            .field("has_retry_policy", &self.retry_policy.is_some())
+            .finish()
+    }
+}
+
+/// Polling schedule for [`Waiter`](crate::client::Waiter)s such as `CvmService::wait_until_running`.
+///
+/// The first poll happens immediately; the delay between polls starts at `poll_interval`, is
+/// multiplied by `backoff` after each attempt up to `max_interval`, and is jittered by ±50% unless
+/// disabled.
+#[derive(Debug, Clone)]
+pub struct WaitOptions {
+    pub(crate) poll_interval: Duration,
+    pub(crate) backoff: f64,
+    pub(crate) max_interval: Duration,
+    pub(crate) jitter: bool,
+    pub(crate) timeout: Duration,
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_WAIT_POLL_INTERVAL,
+            backoff: 1.5,
+            max_interval: DEFAULT_WAIT_MAX_INTERVAL,
+            jitter: true,
+            timeout: DEFAULT_WAIT_TIMEOUT,
+        }
+    }
+}
+
+impl WaitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Delay multiplier applied after each poll; values below `1.0` are treated as `1.0`.
+    pub fn backoff(mut self, multiplier: f64) -> Self {
+        self.backoff = multiplier;
+        self
+    }
+
+    pub fn max_interval(mut self, interval: Duration) -> Self {
+        self.max_interval = interval;
+        self
+    }
+
+    pub fn jitter(mut self, enabled: bool) -> Self {
+        self.jitter = enabled;
+        self
+    }
+
+    /// Total time to wait before failing with [`ErrorKind::WaiterTimeout`](crate::error::ErrorKind::WaiterTimeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Delay before the poll following attempt number `attempt` (1-based).
+    pub(crate) fn delay(&self, attempt: usize) -> Duration {
+        let multiplier = if self.backoff.is_finite() {
+            self.backoff.max(1.0)
+        } else {
+            1.0
+        };
+        backoff_delay(
+            self.poll_interval,
+            multiplier,
+            attempt,
+            self.max_interval.max(self.poll_interval),
+            self.jitter,
+        )
+    }
 }
 
 #[derive(Clone, Eq, PartialEq)]
@@ -76,12 +185,6 @@ impl From<&str> for IdempotencyKey {
     }
 }
 
-#[derive(Debug, Clone)]
-pub(crate) struct RetryConfig {
-    pub(crate) max_retries: usize,
-    pub(crate) base_delay: Duration,
-}
-
 #[derive(Debug, Clone)]
 pub(crate) struct RequestDefaults {
     pub(crate) timeout: Duration,
@@ -95,6 +198,10 @@ pub(crate) struct EndpointConfig {
     pub(crate) host: String,
     pub(crate) port: Option<u16>,
     pub(crate) mode: EndpointMode,
+    /// Per-service hosts that replace the resolved host in every mode but `FixedHost`.
+    pub(crate) service_hosts: HashMap<String, String>,
+    /// Per-service hosts from a `tccli` profile, used only while the endpoint is at its default.
+    pub(crate) profile_hosts: HashMap<String, String>,
 }
 
 impl EndpointConfig {
@@ -160,13 +267,51 @@ impl EndpointConfig {
             host: host.to_string(),
             port: url.port(),
             mode,
+            service_hosts: HashMap::new(),
+            profile_hosts: HashMap::new(),
         })
     }
 
-    pub(crate) fn authority_for_service(&self, service: &str) -> String {
-        let base_host = match self.mode {
-            EndpointMode::ServiceSubdomain => format!("{service}.{}", self.host),
-            EndpointMode::FixedHost => self.host.clone(),
+    /// Rejects modes the base domain cannot serve, e.g. regional hosts under
+    /// `intl.tencentcloudapi.com`.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.mode != EndpointMode::ServiceSubdomain
+            && SERVICE_SUBDOMAIN_ONLY_HOSTS.contains(&self.host.as_str())
+        {
+            return Err(Error::invalid_config(format!(
+                "`{}` only supports EndpointMode::ServiceSubdomain, not {:?}",
+                self.host, self.mode
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether nothing but the service hosts differs from `Client::builder_tencent_cloud`.
+    fn is_default(&self) -> bool {
+        self.mode == EndpointMode::ServiceSubdomain
+            && self.scheme == "https"
+            && self.host == TENCENT_CLOUD_HOST
+            && self.port.is_none()
+    }
+
+    pub(crate) fn authority_for_service(&self, service: &str, region: Option<&str>) -> String {
+        if self.mode != EndpointMode::FixedHost
+            && let Some(host) = self.service_hosts.get(service)
+        {
+            return host.clone();
+        }
+        if self.is_default()
+            && let Some(host) = self.profile_hosts.get(service)
+        {
+            return host.clone();
+        }
+
+        let base_host = match (self.mode, region) {
+            (EndpointMode::Regional, Some(region)) => format!("{service}.{region}.{}", self.host),
+            (EndpointMode::ServiceSubdomain | EndpointMode::Regional, _) => {
+                format!("{service}.{}", self.host)
+            }
+            (EndpointMode::FixedHost, _) => self.host.clone(),
         };
 
         match self.port {
@@ -175,3 +320,145 @@ impl EndpointConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn service_host_overrides_apply_outside_fixed_host_mode() {
+        let mut endpoint = EndpointConfig::from_base_url(
+            "https://tencentcloudapi.com",
+            EndpointMode::ServiceSubdomain,
+        )
+        .unwrap();
+        endpoint.service_hosts.insert(
+            "cvm".to_string(),
+            "cvm.ap-shanghai.tencentcloudapi.com".to_string(),
+        );
+
+        assert_eq!(
+            endpoint.authority_for_service("cvm", None),
+            "cvm.ap-shanghai.tencentcloudapi.com"
+        );
+        assert_eq!(
+            endpoint.authority_for_service("vpc", Some("ap-guangzhou")),
+            "vpc.tencentcloudapi.com"
+        );
+
+        endpoint.mode = EndpointMode::Regional;
+        assert_eq!(
+            endpoint.authority_for_service("cvm", Some("ap-guangzhou")),
+            "cvm.ap-shanghai.tencentcloudapi.com"
+        );
+
+        endpoint.mode = EndpointMode::FixedHost;
+        assert_eq!(
+            endpoint.authority_for_service("cvm", None),
+            "tencentcloudapi.com"
+        );
+    }
+
+    #[test]
+    fn profile_hosts_only_apply_to_the_default_endpoint() {
+        let mut endpoint =
+            EndpointConfig::from_base_url(TENCENT_CLOUD_BASE_URL, EndpointMode::ServiceSubdomain)
+                .unwrap();
+        endpoint.profile_hosts.insert(
+            "cvm".to_string(),
+            "cvm.ap-beijing.tencentcloudapi.com".to_string(),
+        );
+        assert_eq!(
+            endpoint.authority_for_service("cvm", Some("ap-shanghai")),
+            "cvm.ap-beijing.tencentcloudapi.com"
+        );
+
+        endpoint.service_hosts.insert(
+            "cvm".to_string(),
+            "cvm.internal.tencentcloudapi.com".to_string(),
+        );
+        assert_eq!(
+            endpoint.authority_for_service("cvm", Some("ap-shanghai")),
+            "cvm.internal.tencentcloudapi.com"
+        );
+
+        endpoint.service_hosts.clear();
+        endpoint.mode = EndpointMode::Regional;
+        assert_eq!(
+            endpoint.authority_for_service("cvm", Some("ap-shanghai")),
+            "cvm.ap-shanghai.tencentcloudapi.com"
+        );
+
+        let mut endpoint = EndpointConfig::from_base_url(
+            "https://tencentcloudapi.com:8443",
+            EndpointMode::ServiceSubdomain,
+        )
+        .unwrap();
+        endpoint.profile_hosts.insert(
+            "cvm".to_string(),
+            "cvm.ap-beijing.tencentcloudapi.com".to_string(),
+        );
+        assert_eq!(
+            endpoint.authority_for_service("cvm", None),
+            "cvm.tencentcloudapi.com:8443"
+        );
+    }
+
+    #[test]
+    fn presets_only_accept_service_subdomain_mode() {
+        for base_url in [TENCENT_CLOUD_BASE_URL, INTL_BASE_URL, INTERNAL_BASE_URL] {
+            let mut endpoint =
+                EndpointConfig::from_base_url(base_url, EndpointMode::ServiceSubdomain).unwrap();
+            assert!(endpoint.validate().is_ok());
+
+            endpoint.mode = EndpointMode::Regional;
+            assert_eq!(
+                endpoint.validate().is_ok(),
+                base_url == TENCENT_CLOUD_BASE_URL,
+                "{base_url}"
+            );
+        }
+
+        let endpoint =
+            EndpointConfig::from_base_url(INTL_BASE_URL, EndpointMode::ServiceSubdomain).unwrap();
+        assert_eq!(
+            endpoint.authority_for_service("cvm", Some("ap-singapore")),
+            "cvm.intl.tencentcloudapi.com"
+        );
+    }
+
+    #[test]
+    fn regional_mode_puts_region_in_host() {
+        let endpoint = EndpointConfig::from_base_url(
+            "https://tencentcloudapi.com:8443",
+            EndpointMode::Regional,
+        )
+        .unwrap();
+
+        assert_eq!(
+            endpoint.authority_for_service("cvm", Some("ap-shanghai")),
+            "cvm.ap-shanghai.tencentcloudapi.com:8443"
+        );
+        assert_eq!(
+            endpoint.authority_for_service("tag", None),
+            "tag.tencentcloudapi.com:8443"
+        );
+    }
+
+    #[test]
+    fn wait_delay_backs_off_up_to_max_interval() {
+        let options = WaitOptions::new()
+            .poll_interval(Duration::from_secs(2))
+            .backoff(2.0)
+            .max_interval(Duration::from_secs(5))
+            .jitter(false);
+
+        assert_eq!(options.delay(1), Duration::from_secs(2));
+        assert_eq!(options.delay(2), Duration::from_secs(4));
+        assert_eq!(options.delay(3), Duration::from_secs(5));
+        assert_eq!(
+            options.clone().backoff(0.5).delay(3),
+            Duration::from_secs(2)
+        );
+    }
+}
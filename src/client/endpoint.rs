@@ -1,16 +1,31 @@
 use crate::Error;
+use crate::signing::SignatureMethod;
 use crate::types::Region;
 use http::{HeaderMap, Method};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-pub(crate) trait Endpoint {
+/// A single Tencent Cloud API action.
+///
+/// Every request type in [`crate::types`] implements this trait. Implement it for your own
+/// request structs to call actions the SDK does not wrap yet through [`Client::execute`] or
+/// [`BlockingClient::execute`]; they go through the same signing, retry, error classification,
+/// tracing and metrics as the built-in services.
+///
+/// [`Client::execute`]: crate::Client::execute
+/// [`BlockingClient::execute`]: crate::BlockingClient::execute
+pub trait Endpoint {
+    /// Response type decoded from the full response body (including the `Response` envelope).
     type Output: DeserializeOwned + Send + Sync + 'static;
 
     fn method(&self) -> Method {
         Method::POST
     }
 
+    /// Whether the request may be retried safely without an idempotency key.
+    ///
+    /// Defaults to `true` for read-only HTTP methods and for POST actions named
+    /// `Describe*`, `Get*`, `List*` or `Inquiry*`.
     fn is_idempotent(&self) -> bool {
         match self.method() {
             Method::GET | Method::HEAD | Method::PUT | Method::DELETE => true,
@@ -25,10 +40,29 @@ pub(crate) trait Endpoint {
         }
     }
 
-    fn service(&self) -> &'static str;
-    fn action(&self) -> &'static str;
-    fn version(&self) -> &'static str;
+    /// Whether the request is signed with the client's credentials.
+    ///
+    /// Actions such as `sts:AssumeRoleWithWebIdentity` must be sent unsigned; they carry
+    /// `Authorization: SKIP` and work on a client without credentials.
+    fn requires_signing(&self) -> bool {
+        true
+    }
+
+    /// Signature method override; the client's method (TC3 by default) is used when `None`.
+    ///
+    /// Return a legacy method for products that only accept the v1 `Signature` parameter.
+    fn signature_method(&self) -> Option<SignatureMethod> {
+        None
+    }
+
+    /// Product name, e.g. `cvm`. Used for the host and the TC3 credential scope.
+    fn service(&self) -> &str;
+    /// Action name, e.g. `DescribeInstances`.
+    fn action(&self) -> &str;
+    /// API version, e.g. `2017-03-12`.
+    fn version(&self) -> &str;
 
+    /// Region override; the client's default region is used when `None`.
     fn region(&self) -> Option<&Region> {
         None
     }
@@ -41,6 +75,7 @@ pub(crate) trait Endpoint {
         Vec::new()
     }
 
+    /// JSON request body. Defaults to an empty object.
     fn payload(&self) -> Result<Option<Value>, Error> {
         Ok(Some(Value::Object(Default::default())))
     }
@@ -0,0 +1,163 @@
+use crate::{
+    Error, Result,
+    transport::{TransportRequest, TransportResponse},
+};
+use http::{HeaderMap, HeaderName};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// The attempt an [`Interceptor`] hook is called for.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct InterceptorContext<'a> {
+    service: &'a str,
+    action: &'a str,
+    region: Option<&'a str>,
+    attempt: usize,
+    idempotent: bool,
+    started: Instant,
+}
+
+impl<'a> InterceptorContext<'a> {
+    pub(crate) fn new(
+        service: &'a str,
+        action: &'a str,
+        region: Option<&'a str>,
+        attempt: usize,
+        idempotent: bool,
+        started: Instant,
+    ) -> Self {
+        Self {
+            service,
+            action,
+            region,
+            attempt,
+            idempotent,
+            started,
+        }
+    }
+
+    pub fn service(&self) -> &str {
+        self.service
+    }
+
+    pub fn action(&self) -> &str {
+        self.action
+    }
+
+    /// Region the request was resolved to, if any.
+    pub fn region(&self) -> Option<&str> {
+        self.region
+    }
+
+    /// Number of the attempt, starting at `1` and incremented by each retry and by the resend
+    /// after a clock-skew correction.
+    pub fn attempt(&self) -> usize {
+        self.attempt
+    }
+
+    /// Whether the endpoint is idempotent; `false` for mutating actions such as `RunInstances`.
+    pub fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    /// When the request started, before its first attempt.
+    pub fn started(&self) -> Instant {
+        self.started
+    }
+
+    /// Time since the request started, across every attempt so far.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+/// Hooks called around every attempt of every request a client sends.
+///
+/// Install with `ClientBuilder::interceptor` / `BlockingClientBuilder::interceptor`; interceptors
+/// run in the order they were added. Each attempt calls [`before_signing`](Self::before_signing)
+/// and [`after_signing`](Self::after_signing), then either
+/// [`after_response`](Self::after_response) or [`on_error`](Self::on_error), so retries are
+/// visible one by one; an attempt rejected by an open circuit only calls `on_error`. An error
+/// returned from a `before_signing` or `after_signing` hook aborts the request without sending it
+/// and is passed to `on_error`.
+///
+/// ```
+/// use tencent_sdk::client::{Interceptor, InterceptorContext};
+/// use tencent_sdk::transport::TransportResponse;
+///
+/// struct AuditMutations;
+///
+/// impl Interceptor for AuditMutations {
+///     fn after_response(&self, context: &InterceptorContext<'_>, response: &TransportResponse) {
+///         if !context.is_idempotent() {
+///             println!("{} {} -> {}", context.service(), context.action(), response.status);
+///         }
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Adds headers to the attempt before it is signed.
+    ///
+    /// The headers are sent with the attempt but are not covered by the signature. Headers the
+    /// SDK signs or signs with (`Host`, `Content-Type`, `Authorization` and `X-TC-*`) are
+    /// rejected with an `InvalidConfig` error.
+    fn before_signing(
+        &self,
+        _context: &InterceptorContext<'_>,
+        _headers: &mut HeaderMap,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Inspects or alters the signed request. Changing its URL, body, `Host` or
+    /// `Content-Type` invalidates the signature.
+    fn after_signing(
+        &self,
+        _context: &InterceptorContext<'_>,
+        _request: &mut TransportRequest,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with every HTTP response, including error responses that are then retried.
+    fn after_response(&self, _context: &InterceptorContext<'_>, _response: &TransportResponse) {}
+
+    /// Called when an attempt fails with a transport error, an error response, an undecodable
+    /// body or an open circuit, whether or not it is retried.
+    fn on_error(&self, _context: &InterceptorContext<'_>, _error: &Error) {}
+}
+
+/// Whether `name` is one of the headers an interceptor may not set in `before_signing`.
+pub(crate) fn is_signed_header(name: &HeaderName) -> bool {
+    matches!(name.as_str(), "host" | "content-type" | "authorization")
+        || name.as_str().starts_with("x-tc-")
+}
+
+impl<I: Interceptor + ?Sized> Interceptor for Arc<I> {
+    fn before_signing(
+        &self,
+        context: &InterceptorContext<'_>,
+        headers: &mut HeaderMap,
+    ) -> Result<()> {
+        (**self).before_signing(context, headers)
+    }
+
+    fn after_signing(
+        &self,
+        context: &InterceptorContext<'_>,
+        request: &mut TransportRequest,
+    ) -> Result<()> {
+        (**self).after_signing(context, request)
+    }
+
+    fn after_response(&self, context: &InterceptorContext<'_>, response: &TransportResponse) {
+        (**self).after_response(context, response)
+    }
+
+    fn on_error(&self, context: &InterceptorContext<'_>, error: &Error) {
+        (**self).on_error(context, error)
+    }
+}
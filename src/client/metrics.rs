@@ -3,8 +3,8 @@ use http::StatusCode;
 use std::time::Duration;
 
 pub(crate) fn record_success(
-    service: &'static str,
-    action: &'static str,
+    service: &str,
+    action: &str,
     status: StatusCode,
     retries: usize,
     elapsed: Duration,
@@ -13,8 +13,8 @@ pub(crate) fn record_success(
 
     metrics::counter!(
         "tencent_sdk_requests_total",
-        "service" => service,
-        "action" => action,
+        "service" => service.to_string(),
+        "action" => action.to_string(),
         "outcome" => "ok",
         "status" => status,
     )
@@ -22,8 +22,8 @@ pub(crate) fn record_success(
 
     metrics::histogram!(
         "tencent_sdk_request_duration_seconds",
-        "service" => service,
-        "action" => action,
+        "service" => service.to_string(),
+        "action" => action.to_string(),
         "outcome" => "ok",
     )
     .record(elapsed.as_secs_f64());
@@ -31,16 +31,16 @@ pub(crate) fn record_success(
     if retries > 0 {
         metrics::counter!(
             "tencent_sdk_retries_total",
-            "service" => service,
-            "action" => action,
+            "service" => service.to_string(),
+            "action" => action.to_string(),
         )
         .increment(retries as u64);
     }
 }
 
 pub(crate) fn record_error(
-    service: &'static str,
-    action: &'static str,
+    service: &str,
+    action: &str,
     error: &Error,
     retries: usize,
     elapsed: Duration,
@@ -53,8 +53,8 @@ pub(crate) fn record_error(
 
     metrics::counter!(
         "tencent_sdk_requests_total",
-        "service" => service,
-        "action" => action,
+        "service" => service.to_string(),
+        "action" => action.to_string(),
         "outcome" => "error",
         "status" => status,
         "error_kind" => kind,
@@ -63,8 +63,8 @@ pub(crate) fn record_error(
 
     metrics::histogram!(
         "tencent_sdk_request_duration_seconds",
-        "service" => service,
-        "action" => action,
+        "service" => service.to_string(),
+        "action" => action.to_string(),
         "outcome" => "error",
     )
     .record(elapsed.as_secs_f64());
@@ -72,9 +72,27 @@ pub(crate) fn record_error(
     if retries > 0 {
         metrics::counter!(
             "tencent_sdk_retries_total",
-            "service" => service,
-            "action" => action,
+            "service" => service.to_string(),
+            "action" => action.to_string(),
         )
         .increment(retries as u64);
     }
 }
+
+pub(crate) fn record_rate_limit_wait(service: &str, action: &str, wait: Duration) {
+    metrics::histogram!(
+        "tencent_sdk_rate_limit_wait_seconds",
+        "service" => service.to_string(),
+        "action" => action.to_string(),
+    )
+    .record(wait.as_secs_f64());
+}
+
+pub(crate) fn record_circuit_transition(host: &str, state: super::CircuitState) {
+    metrics::counter!(
+        "tencent_sdk_circuit_breaker_transitions_total",
+        "host" => host.to_string(),
+        "state" => state.as_str(),
+    )
+    .increment(1);
+}
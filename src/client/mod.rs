@@ -3,15 +3,38 @@ mod async_client;
 #[cfg(feature = "blocking")]
 mod blocking_client;
 
+mod circuit_breaker;
+mod clock;
 mod common;
-mod config;
+pub(crate) mod config;
 pub(crate) mod endpoint;
+mod interceptor;
 #[cfg(feature = "metrics")]
 pub(crate) mod metrics;
+pub(crate) mod paginator;
+mod rate_limit;
+mod raw;
+mod response;
+mod retry;
+mod waiter;
 
 #[cfg(feature = "async")]
 pub use async_client::{Client, ClientBuilder};
 #[cfg(feature = "blocking")]
 pub use blocking_client::{BlockingClient, BlockingClientBuilder};
 
-pub use config::{EndpointMode, IdempotencyKey, RequestOptions};
+pub use crate::signing::SignatureMethod;
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+pub use config::{EndpointMode, IdempotencyKey, RequestOptions, WaitOptions};
+pub use endpoint::Endpoint;
+pub use interceptor::{Interceptor, InterceptorContext};
+#[cfg(feature = "blocking")]
+pub use paginator::PageIter;
+pub use paginator::{Paginated, Paginator};
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use raw::RawResponse;
+pub use response::{Response, ResponseMetadata};
+pub use retry::{
+    AdaptiveRetryPolicy, NoRetryPolicy, RetryContext, RetryPolicy, StandardRetryPolicy,
+};
+pub use waiter::Waiter;
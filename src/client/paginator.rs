@@ -0,0 +1,292 @@
+use crate::{
+    Result,
+    client::{RequestOptions, endpoint::Endpoint},
+};
+use std::collections::VecDeque;
+
+#[cfg(feature = "blocking")]
+use crate::client::BlockingClient;
+#[cfg(feature = "async")]
+use crate::client::Client;
+#[cfg(feature = "async")]
+use futures_util::stream::{self, Stream};
+
+/// Page size used when neither the request nor the paginator sets one.
+///
+/// Matches the default `Limit` of Tencent Cloud's list actions.
+const DEFAULT_PAGE_SIZE: u64 = 20;
+
+/// A list action paged with `Offset` and `Limit`.
+///
+/// Implemented by the `Describe*` requests that return a `TotalCount` next to a result set;
+/// implement it for your own [`Endpoint`] types to page through them with [`Paginator`].
+pub trait Paginated: Endpoint {
+    /// Element of the result set, e.g. `InstanceSummary` for `DescribeInstances`.
+    type Item;
+
+    /// `Offset` the first page starts from.
+    fn page_offset(&self) -> u64;
+
+    /// `Limit` set on the request, if any; used as the page size unless the paginator overrides it.
+    fn page_limit(&self) -> Option<u64>;
+
+    /// Points the request at the page starting at `offset` with at most `limit` items.
+    fn set_page(&mut self, offset: u64, limit: u64);
+
+    /// Splits a page into its items and `TotalCount`.
+    fn into_page(output: Self::Output) -> (Vec<Self::Item>, Option<u64>);
+}
+
+/// Walks every page of a [`Paginated`] request.
+///
+/// Created with [`Client::paginate`] or [`BlockingClient::paginate`]. Pages are requested
+/// lazily and stop once `TotalCount` items have been seen, a page comes back empty, or
+/// [`max_items`](Self::max_items) is reached. When the response has no `TotalCount`, a page
+/// shorter than the requested `Limit` is treated as the last one.
+///
+/// [`Client::paginate`]: crate::Client::paginate
+/// [`BlockingClient::paginate`]: crate::BlockingClient::paginate
+pub struct Paginator<C, R> {
+    client: C,
+    request: R,
+    options: Option<RequestOptions>,
+    page_size: Option<u64>,
+    max_items: Option<u64>,
+}
+
+impl<C, R: Paginated> Paginator<C, R> {
+    pub(crate) fn new(client: C, request: R) -> Self {
+        Self {
+            client,
+            request,
+            options: None,
+            page_size: None,
+            max_items: None,
+        }
+    }
+
+    /// `Limit` sent with each page; defaults to the request's own limit, or 20.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(u64::from(page_size.max(1)));
+        self
+    }
+
+    /// Stops after yielding `max_items` items, shrinking the last page to fit.
+    pub fn max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Options applied to every page request.
+    pub fn options(mut self, options: RequestOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    fn into_state(self) -> PageState<C, R> {
+        let page_size = self
+            .page_size
+            .or_else(|| self.request.page_limit().filter(|limit| *limit > 0))
+            .unwrap_or(DEFAULT_PAGE_SIZE);
+        let cursor = Cursor {
+            offset: self.request.page_offset(),
+            page_size,
+            remaining: self.max_items,
+            done: false,
+        };
+        PageState {
+            client: self.client,
+            request: self.request,
+            options: self.options,
+            cursor,
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R> Paginator<Client, R>
+where
+    R: Paginated + Send + Sync + 'static,
+    R::Item: Send + 'static,
+{
+    /// Yields the items of every page in order.
+    ///
+    /// A failed page is yielded as an `Err` and ends the stream.
+    pub fn into_stream(self) -> impl Stream<Item = Result<R::Item>> + Send + Unpin + 'static {
+        Box::pin(stream::unfold(self.into_state(), |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+                let limit = state.next_page()?;
+                let result = state
+                    .client
+                    .execute(&state.request, state.options.as_ref())
+                    .await;
+                if let Err(err) = state.finish_page(limit, result) {
+                    return Some((Err(err), state));
+                }
+            }
+        }))
+    }
+}
+
+/// Blocking iterator returned by [`Paginator::into_iter`].
+#[cfg(feature = "blocking")]
+pub struct PageIter<R: Paginated> {
+    state: PageState<BlockingClient, R>,
+}
+
+#[cfg(feature = "blocking")]
+impl<R: Paginated> IntoIterator for Paginator<BlockingClient, R> {
+    type Item = Result<R::Item>;
+    type IntoIter = PageIter<R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PageIter {
+            state: self.into_state(),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<R: Paginated> Iterator for PageIter<R> {
+    type Item = Result<R::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = &mut self.state;
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            let limit = state.next_page()?;
+            let result = state.client.execute(&state.request, state.options.as_ref());
+            if let Err(err) = state.finish_page(limit, result) {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+struct PageState<C, R: Paginated> {
+    client: C,
+    request: R,
+    options: Option<RequestOptions>,
+    cursor: Cursor,
+    buffer: VecDeque<R::Item>,
+}
+
+impl<C, R: Paginated> PageState<C, R> {
+    /// Points the request at the next page and returns its limit, or `None` when done.
+    fn next_page(&mut self) -> Option<u64> {
+        let limit = self.cursor.next_limit()?;
+        self.request.set_page(self.cursor.offset, limit);
+        Some(limit)
+    }
+
+    fn finish_page(&mut self, limit: u64, result: Result<R::Output>) -> Result<()> {
+        let output = result.inspect_err(|_| self.cursor.done = true)?;
+        let (mut items, total_count) = R::into_page(output);
+        self.cursor.advance(limit, &mut items, total_count);
+        self.buffer.extend(items);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Cursor {
+    offset: u64,
+    page_size: u64,
+    remaining: Option<u64>,
+    done: bool,
+}
+
+impl Cursor {
+    fn next_limit(&self) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+        let limit = match self.remaining {
+            Some(remaining) => remaining.min(self.page_size),
+            None => self.page_size,
+        };
+        (limit > 0).then_some(limit)
+    }
+
+    fn advance<T>(&mut self, limit: u64, items: &mut Vec<T>, total_count: Option<u64>) {
+        let received = items.len() as u64;
+        self.offset += received;
+        if let Some(remaining) = self.remaining.as_mut() {
+            items.truncate(usize::try_from(*remaining).unwrap_or(usize::MAX));
+            *remaining -= items.len() as u64;
+        }
+        self.done = received == 0
+            || match total_count {
+                Some(total) => self.offset >= total,
+                None => received < limit,
+            };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(page_size: u64, remaining: Option<u64>) -> Cursor {
+        Cursor {
+            offset: 0,
+            page_size,
+            remaining,
+            done: false,
+        }
+    }
+
+    #[test]
+    fn cursor_stops_at_total_count() {
+        let mut cursor = cursor(2, None);
+        let mut page = vec![1, 2];
+        cursor.advance(2, &mut page, Some(3));
+        assert_eq!(cursor.next_limit(), Some(2));
+        assert_eq!(cursor.offset, 2);
+
+        let mut page = vec![3];
+        cursor.advance(2, &mut page, Some(3));
+        assert_eq!(cursor.next_limit(), None);
+    }
+
+    #[test]
+    fn cursor_keeps_going_when_server_caps_the_page() {
+        let mut cursor = cursor(100, None);
+        let mut page = vec![0; 50];
+        cursor.advance(100, &mut page, Some(120));
+        assert_eq!(cursor.next_limit(), Some(100));
+        assert_eq!(cursor.offset, 50);
+    }
+
+    #[test]
+    fn cursor_without_total_count_stops_on_short_page() {
+        let mut cursor = cursor(2, None);
+        let mut page = vec![1, 2];
+        cursor.advance(2, &mut page, None);
+        assert_eq!(cursor.next_limit(), Some(2));
+
+        let mut page = vec![3];
+        cursor.advance(2, &mut page, None);
+        assert_eq!(cursor.next_limit(), None);
+    }
+
+    #[test]
+    fn cursor_honours_max_items() {
+        let mut cursor = cursor(2, Some(3));
+        assert_eq!(cursor.next_limit(), Some(2));
+        let mut page = vec![1, 2];
+        cursor.advance(2, &mut page, Some(10));
+        assert_eq!(cursor.next_limit(), Some(1));
+
+        let mut page = vec![3, 4];
+        cursor.advance(1, &mut page, Some(10));
+        assert_eq!(page, vec![3]);
+        assert_eq!(cursor.next_limit(), None);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Sustained rate and burst size of one token bucket.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RateLimit {
+    per_second: u32,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// `requests` per second, with a burst of the same size. Zero is treated as one.
+    pub fn per_second(requests: u32) -> Self {
+        let requests = requests.max(1);
+        Self {
+            per_second: requests,
+            burst: requests,
+        }
+    }
+
+    /// Requests that may be sent back to back after an idle period. Zero is treated as one.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+/// Client-side token buckets keyed by service, and optionally by action and region.
+///
+/// Requests wait for a token before they are handed to the transport, so a fan-out job queues
+/// locally instead of tripping `RequestLimitExceeded`. An action limit takes precedence over its
+/// service limit; services without a limit are not throttled. Retries take a token too.
+///
+/// ```
+/// use tencent_sdk::client::{RateLimit, RateLimiter};
+///
+/// let limiter = RateLimiter::new()
+///     .service("cvm", RateLimit::per_second(20))
+///     .action("cvm", "RunInstances", RateLimit::per_second(5).burst(1))
+///     .per_region(true);
+/// ```
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    services: HashMap<String, RateLimit>,
+    actions: HashMap<(String, String), RateLimit>,
+    per_region: bool,
+    buckets: Mutex<HashMap<BucketKey, Bucket>>,
+}
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+struct BucketKey {
+    service: String,
+    action: Option<String>,
+    region: Option<String>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit shared by every action of `service`, e.g. `cvm`.
+    pub fn service(mut self, service: impl Into<String>, limit: RateLimit) -> Self {
+        self.services.insert(service.into(), limit);
+        self
+    }
+
+    /// Limit for a single action, replacing the service limit for it.
+    pub fn action(
+        mut self,
+        service: impl Into<String>,
+        action: impl Into<String>,
+        limit: RateLimit,
+    ) -> Self {
+        self.actions.insert((service.into(), action.into()), limit);
+        self
+    }
+
+    /// Keeps a separate bucket per region, matching how Tencent Cloud counts most QPS limits.
+    pub fn per_region(mut self, enabled: bool) -> Self {
+        self.per_region = enabled;
+        self
+    }
+
+    /// Takes a token and returns how long the caller must wait before sending.
+    pub(crate) fn reserve(&self, service: &str, action: &str, region: Option<&str>) -> Duration {
+        self.reserve_at(service, action, region, Instant::now())
+    }
+
+    fn reserve_at(
+        &self,
+        service: &str,
+        action: &str,
+        region: Option<&str>,
+        now: Instant,
+    ) -> Duration {
+        let (limit, action) = match self.actions.get(&(service.to_string(), action.to_string())) {
+            Some(limit) => (limit, Some(action.to_string())),
+            None => match self.services.get(service) {
+                Some(limit) => (limit, None),
+                None => return Duration::ZERO,
+            },
+        };
+        let key = BucketKey {
+            service: service.to_string(),
+            action,
+            region: region.filter(|_| self.per_region).map(str::to_string),
+        };
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets
+            .entry(key)
+            .or_insert_with(|| Bucket::new(*limit, now))
+            .reserve(now)
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            rate: f64::from(limit.per_second),
+            burst: f64::from(limit.burst),
+            tokens: f64::from(limit.burst),
+            updated: now,
+        }
+    }
+
+    /// Tokens may go negative: each waiter queues behind the ones already holding a reservation.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.updated = self.updated.max(now);
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_burst_then_queues() {
+        let limiter = RateLimiter::new().service("cvm", RateLimit::per_second(10).burst(2));
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve_at("cvm", "A", None, now), Duration::ZERO);
+        assert_eq!(limiter.reserve_at("cvm", "B", None, now), Duration::ZERO);
+        assert_eq!(
+            limiter.reserve_at("cvm", "A", None, now),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            limiter.reserve_at("cvm", "A", None, now),
+            Duration::from_millis(200)
+        );
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(limiter.reserve_at("cvm", "A", None, later), Duration::ZERO);
+    }
+
+    #[test]
+    fn action_limits_take_precedence_and_unlimited_services_pass() {
+        let limiter = RateLimiter::new()
+            .service("cvm", RateLimit::per_second(100))
+            .action("cvm", "RunInstances", RateLimit::per_second(1));
+        let now = Instant::now();
+
+        assert_eq!(
+            limiter.reserve_at("cvm", "RunInstances", None, now),
+            Duration::ZERO
+        );
+        assert_eq!(
+            limiter.reserve_at("cvm", "RunInstances", None, now),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            limiter.reserve_at("cvm", "DescribeInstances", None, now),
+            Duration::ZERO
+        );
+        assert_eq!(
+            limiter.reserve_at("vpc", "DescribeVpcs", None, now),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn per_region_buckets_are_independent() {
+        let limiter = RateLimiter::new()
+            .service("cvm", RateLimit::per_second(1))
+            .per_region(true);
+        let now = Instant::now();
+
+        assert_eq!(
+            limiter.reserve_at("cvm", "A", Some("ap-guangzhou"), now),
+            Duration::ZERO
+        );
+        assert_eq!(
+            limiter.reserve_at("cvm", "A", Some("ap-shanghai"), now),
+            Duration::ZERO
+        );
+        assert_eq!(
+            limiter.reserve_at("cvm", "A", Some("ap-guangzhou"), now),
+            Duration::from_secs(1)
+        );
+    }
+}
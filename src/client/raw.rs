@@ -0,0 +1,120 @@
+use crate::{
+    Error,
+    client::endpoint::Endpoint,
+    types::{Region, RequestId},
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Untyped result of [`Client::call_raw`](crate::Client::call_raw).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawEnvelope")]
+pub struct RawResponse {
+    /// `Response.RequestId`, when present.
+    pub request_id: Option<RequestId>,
+    /// The `Response` object as returned by Tencent Cloud.
+    pub response: Value,
+}
+
+#[derive(Deserialize)]
+struct RawEnvelope {
+    #[serde(rename = "Response")]
+    response: Value,
+}
+
+impl From<RawEnvelope> for RawResponse {
+    fn from(envelope: RawEnvelope) -> Self {
+        let request_id = envelope
+            .response
+            .get("RequestId")
+            .and_then(Value::as_str)
+            .map(RequestId::from);
+
+        Self {
+            request_id,
+            response: envelope.response,
+        }
+    }
+}
+
+pub(crate) struct RawEndpoint<'a> {
+    service: &'a str,
+    version: &'a str,
+    action: &'a str,
+    region: Option<Region>,
+    payload: Value,
+}
+
+impl<'a> RawEndpoint<'a> {
+    pub(crate) fn new(
+        service: &'a str,
+        version: &'a str,
+        action: &'a str,
+        region: Option<&str>,
+        payload: Value,
+    ) -> Self {
+        Self {
+            service,
+            version,
+            action,
+            region: region.map(Region::from),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for RawEndpoint<'_> {
+    type Output = RawResponse;
+
+    fn service(&self) -> &str {
+        self.service
+    }
+
+    fn action(&self) -> &str {
+        self.action
+    }
+
+    fn version(&self) -> &str {
+        self.version
+    }
+
+    fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    fn payload(&self) -> Result<Option<Value>, Error> {
+        Ok(Some(self.payload.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn raw_response_extracts_request_id() {
+        let parsed: RawResponse = serde_json::from_value(json!({
+            "Response": {
+                "TotalCount": 1,
+                "RequestId": "req-raw"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            parsed.request_id.as_ref().map(RequestId::as_str),
+            Some("req-raw")
+        );
+        assert_eq!(parsed.response["TotalCount"], json!(1));
+    }
+
+    #[test]
+    fn raw_endpoint_is_idempotent_for_describe_actions() {
+        let describe = RawEndpoint::new("cvm", "2017-03-12", "DescribeZones", None, json!({}));
+        let run = RawEndpoint::new("cvm", "2017-03-12", "RunInstances", None, json!({}));
+
+        assert!(describe.is_idempotent());
+        assert!(!run.is_idempotent());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::types::{Region, RequestId};
+use http::{HeaderMap, StatusCode};
+use std::time::Duration;
+
+/// Typed output of a request together with its [`ResponseMetadata`].
+///
+/// Returned by `Client::execute_with_metadata` / `BlockingClient::execute_with_metadata`.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    output: T,
+    metadata: ResponseMetadata,
+}
+
+impl<T> Response<T> {
+    pub(crate) fn new(output: T, metadata: ResponseMetadata) -> Self {
+        Self { output, metadata }
+    }
+
+    pub fn output(&self) -> &T {
+        &self.output
+    }
+
+    pub fn metadata(&self) -> &ResponseMetadata {
+        &self.metadata
+    }
+
+    pub fn into_output(self) -> T {
+        self.output
+    }
+
+    pub fn into_parts(self) -> (T, ResponseMetadata) {
+        (self.output, self.metadata)
+    }
+}
+
+/// What a support ticket needs about a successful request: where it went, how it was answered
+/// and how long it took.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponseMetadata {
+    request_id: Option<RequestId>,
+    status: StatusCode,
+    headers: HeaderMap,
+    attempts: usize,
+    elapsed: Duration,
+    host: String,
+    region: Option<Region>,
+}
+
+impl ResponseMetadata {
+    pub(crate) fn new(
+        request_id: Option<RequestId>,
+        status: StatusCode,
+        headers: HeaderMap,
+        attempts: usize,
+        elapsed: Duration,
+        host: String,
+        region: Option<Region>,
+    ) -> Self {
+        Self {
+            request_id,
+            status,
+            headers,
+            attempts,
+            elapsed,
+            host,
+            region,
+        }
+    }
+
+    /// `Response.RequestId`, or the request id header when the body has none.
+    pub fn request_id(&self) -> Option<&RequestId> {
+        self.request_id.as_ref()
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Headers of the final response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Attempts made, counting retries and a resend after clock-skew correction; `1` when the
+    /// first attempt succeeded.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// Time from the first attempt to the decoded response, including retry delays.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Host the request was signed for and sent to, e.g. `cvm.ap-shanghai.tencentcloudapi.com`.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Region the request was resolved to, if any.
+    pub fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+}
@@ -0,0 +1,366 @@
+use crate::{Error, error::ErrorKind, util::retry_delay};
+use http::StatusCode;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// A failed attempt, as seen by a [`RetryPolicy`].
+#[non_exhaustive]
+pub struct RetryContext<'a> {
+    attempt: usize,
+    error: &'a Error,
+    retry_after: Option<Duration>,
+    idempotent: bool,
+    service: &'a str,
+    action: &'a str,
+}
+
+impl<'a> RetryContext<'a> {
+    pub(crate) fn new(
+        attempt: usize,
+        error: &'a Error,
+        retry_after: Option<Duration>,
+        idempotent: bool,
+        service: &'a str,
+        action: &'a str,
+    ) -> Self {
+        Self {
+            attempt,
+            error,
+            retry_after,
+            idempotent,
+            service,
+            action,
+        }
+    }
+
+    /// Number of the retry being considered: `1` after the first attempt fails. A resend after a
+    /// clock-skew correction is not a retry and does not count.
+    pub fn attempt(&self) -> usize {
+        self.attempt
+    }
+
+    pub fn error(&self) -> &Error {
+        self.error
+    }
+
+    /// HTTP status of the failed attempt; `None` for transport errors.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.error.status()
+    }
+
+    /// Delay requested by the server through `Retry-After`.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    /// Whether resending is safe: the endpoint is idempotent or the request carries an
+    /// idempotency key.
+    pub fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    pub fn service(&self) -> &str {
+        self.service
+    }
+
+    pub fn action(&self) -> &str {
+        self.action
+    }
+}
+
+impl fmt::Debug for RetryContext<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryContext")
+            .field("attempt", &self.attempt)
+            .field("kind", &self.error.kind())
+            .field("status", &self.status())
+            .field("retry_after", &self.retry_after)
+            .field("idempotent", &self.idempotent)
+            .field("service", &self.service)
+            .field("action", &self.action)
+            .finish()
+    }
+}
+
+/// Decides whether a failed request is sent again and after how long.
+///
+/// Set one per client with `ClientBuilder::retry_policy` or per request with
+/// [`RequestOptions::retry_policy`](crate::client::RequestOptions::retry_policy). Clock-skew
+/// corrections are resent independently of the policy.
+pub trait RetryPolicy: Send + Sync {
+    /// Delay before the next attempt, or `None` to return the error.
+    fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration>;
+
+    /// Called when a request completes successfully.
+    fn on_success(&self) {}
+}
+
+impl<P: RetryPolicy + ?Sized> RetryPolicy for Arc<P> {
+    fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+        (**self).retry_delay(context)
+    }
+
+    fn on_success(&self) {
+        (**self).on_success()
+    }
+}
+
+/// Retries idempotent requests on throttling, transport failures and 502/503/504, with
+/// exponential backoff and jitter. Honours `Retry-After`.
+#[derive(Debug, Clone)]
+pub struct StandardRetryPolicy {
+    max_retries: usize,
+    base_delay: Duration,
+}
+
+impl StandardRetryPolicy {
+    pub fn new(max_retries: usize, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+        }
+    }
+
+    fn should_retry(&self, context: &RetryContext<'_>) -> bool {
+        context.attempt <= self.max_retries
+            && context.idempotent
+            && (context.error.is_retryable() || context.status().is_some_and(is_retryable_status))
+    }
+
+    fn delay(&self, context: &RetryContext<'_>) -> Duration {
+        context
+            .retry_after
+            .unwrap_or_else(|| retry_delay(self.base_delay, context.attempt))
+    }
+}
+
+impl RetryPolicy for StandardRetryPolicy {
+    fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+        self.should_retry(context).then(|| self.delay(context))
+    }
+}
+
+const DEFAULT_RETRY_BUDGET: u32 = 500;
+const RETRY_COST: u32 = 5;
+const TRANSPORT_RETRY_COST: u32 = 10;
+const MAX_THROTTLE_BACKOFF_EXPONENT: u32 = 4;
+
+/// [`StandardRetryPolicy`] with a retry budget shared by every request using the policy.
+///
+/// Each retry spends tokens from the budget (more for transport failures) and each success
+/// returns one, so a failing dependency stops being retried instead of being amplified.
+/// Consecutive throttling errors also stretch the backoff, up to 16 times the standard delay.
+#[derive(Debug)]
+pub struct AdaptiveRetryPolicy {
+    standard: StandardRetryPolicy,
+    capacity: u32,
+    state: Mutex<AdaptiveState>,
+}
+
+#[derive(Debug)]
+struct AdaptiveState {
+    tokens: u32,
+    throttled: u32,
+}
+
+impl AdaptiveRetryPolicy {
+    pub fn new(max_retries: usize, base_delay: Duration) -> Self {
+        Self {
+            standard: StandardRetryPolicy::new(max_retries, base_delay),
+            capacity: DEFAULT_RETRY_BUDGET,
+            state: Mutex::new(AdaptiveState {
+                tokens: DEFAULT_RETRY_BUDGET,
+                throttled: 0,
+            }),
+        }
+    }
+
+    /// Size of the retry budget; a retry costs 5 tokens, or 10 after a transport failure.
+    pub fn retry_budget(mut self, tokens: u32) -> Self {
+        self.capacity = tokens;
+        self.state
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .tokens = tokens;
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, AdaptiveState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl RetryPolicy for AdaptiveRetryPolicy {
+    fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+        if !self.standard.should_retry(context) {
+            return None;
+        }
+
+        let cost = if context.error.kind() == ErrorKind::Transport {
+            TRANSPORT_RETRY_COST
+        } else {
+            RETRY_COST
+        };
+        let mut state = self.lock();
+        state.tokens = state.tokens.checked_sub(cost)?;
+
+        // Only throttles that lead to a retry grow the backoff.
+        let throttled = context.error.kind() == ErrorKind::RateLimited;
+        if throttled {
+            state.throttled = state.throttled.saturating_add(1);
+        }
+        let delay = self.standard.delay(context);
+        if throttled && context.retry_after.is_none() {
+            let exponent = (state.throttled - 1).min(MAX_THROTTLE_BACKOFF_EXPONENT);
+            Some(delay.saturating_mul(1 << exponent))
+        } else {
+            Some(delay)
+        }
+    }
+
+    fn on_success(&self) {
+        let mut state = self.lock();
+        state.tokens = state.tokens.saturating_add(1).min(self.capacity);
+        state.throttled = 0;
+    }
+}
+
+/// Never retries.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRetryPolicy;
+
+impl RetryPolicy for NoRetryPolicy {
+    fn retry_delay(&self, _context: &RetryContext<'_>) -> Option<Duration> {
+        None
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::Method;
+
+    fn api_error(status: StatusCode, code: Option<&str>) -> Error {
+        Error::api(
+            Some(status),
+            Method::POST,
+            "cvm.tencentcloudapi.com",
+            "/",
+            code.map(str::to_string),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn context(attempt: usize, error: &Error, idempotent: bool) -> RetryContext<'_> {
+        RetryContext::new(attempt, error, None, idempotent, "cvm", "DescribeInstances")
+    }
+
+    #[test]
+    fn standard_policy_retries_idempotent_retryable_errors_only() {
+        let policy = StandardRetryPolicy::new(2, Duration::ZERO);
+        let unavailable = api_error(StatusCode::SERVICE_UNAVAILABLE, None);
+        let invalid = api_error(StatusCode::OK, Some("InvalidParameter"));
+
+        assert_eq!(
+            policy.retry_delay(&context(1, &unavailable, true)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(policy.retry_delay(&context(3, &unavailable, true)), None);
+        assert_eq!(policy.retry_delay(&context(1, &unavailable, false)), None);
+        assert_eq!(policy.retry_delay(&context(1, &invalid, true)), None);
+    }
+
+    #[test]
+    fn standard_policy_prefers_retry_after() {
+        let policy = StandardRetryPolicy::new(1, Duration::from_secs(60));
+        let throttled = api_error(StatusCode::TOO_MANY_REQUESTS, None);
+        let context = RetryContext::new(
+            1,
+            &throttled,
+            Some(Duration::from_secs(2)),
+            true,
+            "cvm",
+            "DescribeInstances",
+        );
+
+        assert_eq!(policy.retry_delay(&context), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn adaptive_policy_stops_when_budget_is_spent() {
+        let policy = AdaptiveRetryPolicy::new(5, Duration::ZERO).retry_budget(10);
+        let unavailable = api_error(StatusCode::SERVICE_UNAVAILABLE, None);
+
+        assert!(
+            policy
+                .retry_delay(&context(1, &unavailable, true))
+                .is_some()
+        );
+        assert!(
+            policy
+                .retry_delay(&context(2, &unavailable, true))
+                .is_some()
+        );
+        assert_eq!(policy.retry_delay(&context(3, &unavailable, true)), None);
+
+        for _ in 0..5 {
+            policy.on_success();
+        }
+        assert!(
+            policy
+                .retry_delay(&context(1, &unavailable, true))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn adaptive_policy_backs_off_further_on_repeated_throttling() {
+        let policy = AdaptiveRetryPolicy::new(5, Duration::from_millis(100));
+        let throttled = api_error(StatusCode::OK, Some("RequestLimitExceeded"));
+
+        let first = policy.retry_delay(&context(1, &throttled, true)).unwrap();
+        let third = {
+            policy.retry_delay(&context(1, &throttled, true)).unwrap();
+            policy.retry_delay(&context(1, &throttled, true)).unwrap()
+        };
+        assert!(first < Duration::from_millis(150));
+        assert!(third >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn adaptive_policy_ignores_throttles_it_does_not_retry() {
+        let policy = AdaptiveRetryPolicy::new(1, Duration::from_millis(100));
+        let throttled = api_error(StatusCode::OK, Some("RequestLimitExceeded"));
+
+        for _ in 0..3 {
+            assert_eq!(policy.retry_delay(&context(2, &throttled, true)), None);
+        }
+        let delay = policy.retry_delay(&context(1, &throttled, true)).unwrap();
+        assert!(delay < Duration::from_millis(150));
+    }
+
+    #[test]
+    fn no_retry_policy_never_retries() {
+        let unavailable = api_error(StatusCode::SERVICE_UNAVAILABLE, None);
+        assert_eq!(
+            NoRetryPolicy.retry_delay(&context(1, &unavailable, true)),
+            None
+        );
+    }
+}
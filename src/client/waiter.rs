@@ -0,0 +1,294 @@
+use crate::{Error, Result, client::WaitOptions, error::ObservedState};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "async")]
+use std::future::Future;
+
+type OutputMatcher<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+type ErrorMatcher = Box<dyn Fn(&Error) -> bool + Send + Sync>;
+type Observer<T> = Box<dyn Fn(&T) -> Vec<ObservedState> + Send + Sync>;
+
+/// Polls a describe call until an acceptor decides the outcome.
+///
+/// Acceptors are checked in the order they were added and the first match wins. A response
+/// nothing matches is polled again; an error nothing matches is returned as is. Polls are spaced
+/// by [`WaitOptions`]; once its timeout elapses the waiter fails with
+/// [`ErrorKind::WaiterTimeout`](crate::error::ErrorKind::WaiterTimeout), and a failure acceptor
+/// fails it with [`ErrorKind::WaiterFailed`](crate::error::ErrorKind::WaiterFailed). Both carry
+/// the states reported by [`observe`](Self::observe) for the last response.
+///
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # async fn demo(client: tencent_sdk::Client) -> tencent_sdk::Result<()> {
+/// use tencent_sdk::client::{WaitOptions, Waiter};
+/// use tencent_sdk::types::ssl::{DescribeCertificateRequest, DescribeCertificateResponse};
+///
+/// let waiter = Waiter::<DescribeCertificateResponse>::new("CertificateIssued")
+///     .options(WaitOptions::new().timeout(std::time::Duration::from_secs(1800)))
+///     .success(|resp| resp.response.status == Some(1))
+///     .failure(|resp| resp.response.status == Some(2));
+/// let request = DescribeCertificateRequest::new("cert-id");
+/// client.wait_until(&request, &waiter).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Waiter<T> {
+    name: String,
+    options: WaitOptions,
+    acceptors: Vec<Acceptor<T>>,
+    observer: Option<Observer<T>>,
+}
+
+enum Acceptor<T> {
+    Output(WaitState, OutputMatcher<T>),
+    Error(WaitState, ErrorMatcher),
+}
+
+#[derive(Clone, Copy)]
+enum WaitState {
+    Success,
+    Failure,
+    Retry,
+}
+
+/// What a single poll decided.
+enum Step<T> {
+    Done(Result<Option<T>>),
+    Retry,
+}
+
+impl<T> Waiter<T> {
+    /// `name` identifies the waiter in errors, e.g. `InstanceRunning`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            options: WaitOptions::default(),
+            acceptors: Vec::new(),
+            observer: None,
+        }
+    }
+
+    pub fn options(mut self, options: WaitOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Stops with the response once `matches` returns `true`.
+    pub fn success(mut self, matches: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.acceptors
+            .push(Acceptor::Output(WaitState::Success, Box::new(matches)));
+        self
+    }
+
+    /// Fails with [`ErrorKind::WaiterFailed`](crate::error::ErrorKind::WaiterFailed) once
+    /// `matches` returns `true`.
+    pub fn failure(mut self, matches: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.acceptors
+            .push(Acceptor::Output(WaitState::Failure, Box::new(matches)));
+        self
+    }
+
+    /// Polls again when `matches` returns `true`, ahead of acceptors added later.
+    pub fn retry(mut self, matches: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.acceptors
+            .push(Acceptor::Output(WaitState::Retry, Box::new(matches)));
+        self
+    }
+
+    /// Stops without a response when the describe call fails with a matching error, e.g. a
+    /// `NotFound` error while waiting for a deletion.
+    pub fn success_on_error(
+        mut self,
+        matches: impl Fn(&Error) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.acceptors
+            .push(Acceptor::Error(WaitState::Success, Box::new(matches)));
+        self
+    }
+
+    /// Polls again when the describe call fails with a matching error.
+    pub fn retry_on_error(
+        mut self,
+        matches: impl Fn(&Error) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.acceptors
+            .push(Acceptor::Error(WaitState::Retry, Box::new(matches)));
+        self
+    }
+
+    /// Describes each resource in a response for timeout and failure errors.
+    pub fn observe(
+        mut self,
+        observe: impl Fn(&T) -> Vec<ObservedState> + Send + Sync + 'static,
+    ) -> Self {
+        self.observer = Some(Box::new(observe));
+        self
+    }
+
+    /// Polls `describe` until an acceptor matches.
+    ///
+    /// Returns `None` when a [`success_on_error`](Self::success_on_error) acceptor ended the wait.
+    #[cfg(feature = "async")]
+    pub async fn wait<F, Fut>(&self, mut describe: F) -> Result<Option<T>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut run = Run::new();
+        loop {
+            if let Step::Done(result) = self.step(&mut run, describe().await) {
+                return result;
+            }
+            tokio::time::sleep(self.next_delay(&run)?).await;
+        }
+    }
+
+    /// Blocking version of [`wait`](Self::wait).
+    #[cfg(feature = "blocking")]
+    pub fn wait_blocking<F>(&self, mut describe: F) -> Result<Option<T>>
+    where
+        F: FnMut() -> Result<T>,
+    {
+        let mut run = Run::new();
+        loop {
+            if let Step::Done(result) = self.step(&mut run, describe()) {
+                return result;
+            }
+            std::thread::sleep(self.next_delay(&run)?);
+        }
+    }
+
+    fn step(&self, run: &mut Run, result: Result<T>) -> Step<T> {
+        run.attempts += 1;
+        let state = match &result {
+            Ok(output) => {
+                if let Some(observer) = &self.observer {
+                    run.last_states = observer(output);
+                }
+                self.acceptors.iter().find_map(|acceptor| match acceptor {
+                    Acceptor::Output(state, matches) if matches(output) => Some(*state),
+                    _ => None,
+                })
+            }
+            Err(err) => self.acceptors.iter().find_map(|acceptor| match acceptor {
+                Acceptor::Error(state, matches) if matches(err) => Some(*state),
+                _ => None,
+            }),
+        };
+
+        match (state, result) {
+            (Some(WaitState::Success), result) => Step::Done(Ok(result.ok())),
+            (Some(WaitState::Failure), _) => Step::Done(Err(Error::waiter_failed(
+                self.name.clone(),
+                run.started.elapsed(),
+                run.attempts,
+                std::mem::take(&mut run.last_states),
+            ))),
+            (Some(WaitState::Retry), _) | (None, Ok(_)) => Step::Retry,
+            (None, Err(err)) => Step::Done(Err(err)),
+        }
+    }
+
+    /// Delay before the next poll, or the timeout error once the deadline has passed.
+    fn next_delay(&self, run: &Run) -> Result<Duration> {
+        let elapsed = run.started.elapsed();
+        let Some(remaining) = self
+            .options
+            .timeout
+            .checked_sub(elapsed)
+            .filter(|remaining| !remaining.is_zero())
+        else {
+            return Err(Error::waiter_timeout(
+                self.name.clone(),
+                elapsed,
+                run.attempts,
+                run.last_states.clone(),
+            ));
+        };
+        Ok(self.options.delay(run.attempts as usize).min(remaining))
+    }
+}
+
+struct Run {
+    started: Instant,
+    attempts: u32,
+    last_states: Vec<ObservedState>,
+}
+
+impl Run {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            attempts: 0,
+            last_states: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    fn run_steps(waiter: &Waiter<u32>, results: Vec<Result<u32>>) -> Vec<&'static str> {
+        let mut run = Run::new();
+        results
+            .into_iter()
+            .map(|result| match waiter.step(&mut run, result) {
+                Step::Retry => "retry",
+                Step::Done(Ok(Some(_))) => "success",
+                Step::Done(Ok(None)) => "success-without-output",
+                Step::Done(Err(err)) if err.kind() == ErrorKind::WaiterFailed => "failure",
+                Step::Done(Err(_)) => "error",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_matching_acceptor_wins() {
+        let waiter = Waiter::new("Test")
+            .retry(|value: &u32| *value == 3)
+            .success(|value| *value >= 3)
+            .failure(|value| *value == 0);
+
+        assert_eq!(
+            run_steps(&waiter, vec![Ok(1), Ok(3), Ok(4), Ok(0)]),
+            ["retry", "retry", "success", "failure"]
+        );
+    }
+
+    #[test]
+    fn unmatched_errors_are_returned() {
+        let waiter = Waiter::new("Test")
+            .success(|value: &u32| *value == 1)
+            .retry_on_error(|err| err.message() == Some("retry me"))
+            .success_on_error(|err| err.message() == Some("gone"));
+
+        assert_eq!(
+            run_steps(
+                &waiter,
+                vec![
+                    Err(Error::invalid_config("retry me")),
+                    Err(Error::invalid_config("gone")),
+                    Err(Error::invalid_config("boom")),
+                ]
+            ),
+            ["retry", "success-without-output", "error"]
+        );
+    }
+
+    #[test]
+    fn deadline_reports_last_observed_states() {
+        let waiter = Waiter::new("Test")
+            .options(WaitOptions::new().timeout(Duration::ZERO))
+            .observe(|value: &u32| {
+                vec![ObservedState::new("res-1", Some(value.to_string()), None)]
+            });
+        let mut run = Run::new();
+        assert!(matches!(waiter.step(&mut run, Ok(7)), Step::Retry));
+
+        let err = waiter.next_delay(&run).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WaiterTimeout);
+        assert!(err.to_string().contains("res-1=7"));
+    }
+}
@@ -16,6 +16,9 @@ pub enum ErrorKind {
     Conflict,
     RateLimited,
     Api,
+    WaiterTimeout,
+    WaiterFailed,
+    CircuitOpen,
 }
 
 #[non_exhaustive]
@@ -215,6 +218,124 @@ impl fmt::Debug for RateLimitedError {
     }
 }
 
+/// Last state a waiter saw for one resource before giving up.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ObservedState {
+    resource_id: String,
+    state: Option<String>,
+    operation_state: Option<String>,
+}
+
+impl ObservedState {
+    pub fn new(
+        resource_id: impl Into<String>,
+        state: Option<String>,
+        operation_state: Option<String>,
+    ) -> Self {
+        Self {
+            resource_id: resource_id.into(),
+            state,
+            operation_state,
+        }
+    }
+
+    pub fn resource_id(&self) -> &str {
+        &self.resource_id
+    }
+
+    /// Reported state, or `None` when the resource was missing from the response.
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// State of the last operation on the resource, e.g. CVM `LatestOperationState`.
+    pub fn operation_state(&self) -> Option<&str> {
+        self.operation_state.as_deref()
+    }
+}
+
+impl fmt::Display for ObservedState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}={}",
+            self.resource_id,
+            self.state.as_deref().unwrap_or("<missing>")
+        )?;
+        if let Some(operation_state) = self.operation_state.as_deref() {
+            write!(f, " ({operation_state})")?;
+        }
+        Ok(())
+    }
+}
+
+/// A waiter that gave up, either on its deadline or on a failure acceptor.
+#[non_exhaustive]
+pub struct WaiterError {
+    waiter: String,
+    elapsed: Duration,
+    attempts: u32,
+    last_states: Vec<ObservedState>,
+}
+
+impl WaiterError {
+    /// Name of the waiter, e.g. `InstanceRunning`.
+    pub fn waiter(&self) -> &str {
+        &self.waiter
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn last_states(&self) -> &[ObservedState] {
+        &self.last_states
+    }
+}
+
+impl fmt::Debug for WaiterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaiterError")
+            .field("waiter", &self.waiter)
+            .field("elapsed", &self.elapsed)
+            .field("attempts", &self.attempts)
+            .field("last_states", &self.last_states)
+            .finish()
+    }
+}
+
+/// A request rejected locally because the circuit for its host is open.
+#[non_exhaustive]
+pub struct CircuitOpenError {
+    host: String,
+    retry_after: Duration,
+}
+
+impl CircuitOpenError {
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Time left until the circuit lets a probe through.
+    pub fn retry_after(&self) -> Duration {
+        self.retry_after
+    }
+}
+
+impl fmt::Debug for CircuitOpenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircuitOpenError")
+            .field("host", &self.host)
+            .field("retry_after", &self.retry_after)
+            .finish()
+    }
+}
+
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum Error {
@@ -226,6 +347,9 @@ pub enum Error {
     Conflict(Box<ApiError>),
     RateLimited(Box<RateLimitedError>),
     Api(Box<ApiError>),
+    WaiterTimeout(Box<WaiterError>),
+    WaiterFailed(Box<WaiterError>),
+    CircuitOpen(Box<CircuitOpenError>),
 }
 
 impl Error {
@@ -239,6 +363,9 @@ impl Error {
             Error::Conflict(_) => ErrorKind::Conflict,
             Error::RateLimited(_) => ErrorKind::RateLimited,
             Error::Api(_) => ErrorKind::Api,
+            Error::WaiterTimeout(_) => ErrorKind::WaiterTimeout,
+            Error::WaiterFailed(_) => ErrorKind::WaiterFailed,
+            Error::CircuitOpen(_) => ErrorKind::CircuitOpen,
         }
     }
 
@@ -252,6 +379,7 @@ impl Error {
             Error::Conflict(err) => err.status,
             Error::RateLimited(err) => err.api.status,
             Error::Api(err) => err.status,
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
@@ -265,6 +393,7 @@ impl Error {
             Error::Conflict(err) => err.method.as_ref(),
             Error::RateLimited(err) => err.api.method.as_ref(),
             Error::Api(err) => err.method.as_ref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
@@ -278,6 +407,8 @@ impl Error {
             Error::Conflict(err) => err.host.as_deref(),
             Error::RateLimited(err) => err.api.host.as_deref(),
             Error::Api(err) => err.host.as_deref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) => None,
+            Error::CircuitOpen(err) => Some(&err.host),
         }
     }
 
@@ -291,6 +422,7 @@ impl Error {
             Error::Conflict(err) => err.path.as_deref(),
             Error::RateLimited(err) => err.api.path.as_deref(),
             Error::Api(err) => err.path.as_deref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
@@ -304,6 +436,7 @@ impl Error {
             Error::Conflict(err) => err.message.as_deref(),
             Error::RateLimited(err) => err.api.message.as_deref(),
             Error::Api(err) => err.message.as_deref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
@@ -317,6 +450,7 @@ impl Error {
             Error::Conflict(err) => err.request_id.as_deref(),
             Error::RateLimited(err) => err.api.request_id.as_deref(),
             Error::Api(err) => err.request_id.as_deref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
@@ -330,6 +464,7 @@ impl Error {
             Error::Conflict(err) => err.code.as_deref(),
             Error::RateLimited(err) => err.api.code.as_deref(),
             Error::Api(err) => err.code.as_deref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
@@ -343,12 +478,14 @@ impl Error {
             Error::Conflict(err) => err.body_snippet.as_deref(),
             Error::RateLimited(err) => err.api.body_snippet.as_deref(),
             Error::Api(err) => err.body_snippet.as_deref(),
+            Error::WaiterTimeout(_) | Error::WaiterFailed(_) | Error::CircuitOpen(_) => None,
         }
     }
 
     pub fn retry_after(&self) -> Option<Duration> {
         match self {
             Error::RateLimited(err) => err.retry_after,
+            Error::CircuitOpen(err) => Some(err.retry_after),
             _ => None,
         }
     }
@@ -369,6 +506,14 @@ impl Error {
         }
     }
 
+    pub(crate) fn invalid_config(message: impl Into<String>) -> Self {
+        Self::InvalidConfig(Box::new(InvalidConfigError {
+            message: message.into(),
+            base_url: None,
+            source: None,
+        }))
+    }
+
     pub(crate) fn invalid_base_url(
         base_url: impl Into<String>,
         source: impl Into<BoxError>,
@@ -380,7 +525,8 @@ impl Error {
         }))
     }
 
-    pub(crate) fn invalid_request_with_source(
+    /// Builds an [`ErrorKind::InvalidConfig`] error, e.g. for a custom `Endpoint` payload that fails to serialize.
+    pub fn invalid_config_with_source(
         message: impl Into<String>,
         source: impl Into<BoxError>,
     ) -> Self {
@@ -468,6 +614,41 @@ impl Error {
         }
     }
 
+    pub(crate) fn waiter_timeout(
+        waiter: impl Into<String>,
+        elapsed: Duration,
+        attempts: u32,
+        last_states: Vec<ObservedState>,
+    ) -> Self {
+        Self::WaiterTimeout(Box::new(WaiterError {
+            waiter: waiter.into(),
+            elapsed,
+            attempts,
+            last_states,
+        }))
+    }
+
+    pub(crate) fn waiter_failed(
+        waiter: impl Into<String>,
+        elapsed: Duration,
+        attempts: u32,
+        last_states: Vec<ObservedState>,
+    ) -> Self {
+        Self::WaiterFailed(Box::new(WaiterError {
+            waiter: waiter.into(),
+            elapsed,
+            attempts,
+            last_states,
+        }))
+    }
+
+    pub(crate) fn circuit_open(host: impl Into<String>, retry_after: Duration) -> Self {
+        Self::CircuitOpen(Box::new(CircuitOpenError {
+            host: host.into(),
+            retry_after,
+        }))
+    }
+
     pub(crate) fn signing(source: impl Into<BoxError>) -> Self {
         Self::InvalidConfig(Box::new(InvalidConfigError {
             message: "signing error".to_string(),
@@ -510,6 +691,13 @@ impl fmt::Display for Error {
                 Ok(())
             }
             Error::Api(err) => write_api_error(f, "api error", err),
+            Error::WaiterTimeout(err) => write_waiter_error(f, "timed out", err),
+            Error::WaiterFailed(err) => write_waiter_error(f, "reached a failure state", err),
+            Error::CircuitOpen(err) => write!(
+                f,
+                "circuit open for {} (retry after {:?})",
+                err.host, err.retry_after
+            ),
         }
     }
 }
@@ -544,6 +732,19 @@ fn write_api_error(f: &mut fmt::Formatter<'_>, label: &str, err: &ApiError) -> f
     Ok(())
 }
 
+fn write_waiter_error(f: &mut fmt::Formatter<'_>, outcome: &str, err: &WaiterError) -> fmt::Result {
+    write!(
+        f,
+        "waiter {} {outcome} after {:?} ({} attempts)",
+        err.waiter, err.elapsed, err.attempts
+    )?;
+    for (index, state) in err.last_states.iter().enumerate() {
+        f.write_str(if index == 0 { ": " } else { ", " })?;
+        write!(f, "{state}")?;
+    }
+    Ok(())
+}
+
 fn classify_api_error(err: &ApiError) -> ErrorKind {
     if err.status == Some(StatusCode::TOO_MANY_REQUESTS) {
         return ErrorKind::RateLimited;
@@ -643,5 +844,17 @@ fn is_retryable_transport_source(source: &(dyn StdError + 'static)) -> bool {
         };
     }
 
+    if let Some(io) = source.downcast_ref::<std::io::Error>() {
+        return matches!(
+            io.kind(),
+            std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::ConnectionRefused
+                | std::io::ErrorKind::NotConnected
+                | std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::UnexpectedEof
+        );
+    }
+
     false
 }
@@ -48,17 +48,19 @@ compile_error!("Enable only one of: rustls, native-tls.");
 #[cfg(all(feature = "blocking-rustls", feature = "blocking-native-tls"))]
 compile_error!("Enable only one of: blocking-rustls, blocking-native-tls.");
 
-mod signing;
-mod transport;
 mod util;
 
 pub mod api;
 pub mod auth;
 pub mod client;
 pub mod error;
+pub mod signing;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
 pub mod types;
 
-pub use auth::Auth;
+pub use auth::{Auth, Credentials, CredentialsProvider};
 #[cfg(feature = "blocking")]
 pub use client::BlockingClient;
 #[cfg(feature = "async")]
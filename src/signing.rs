@@ -1,11 +1,62 @@
-use crate::{Error, auth::Auth};
+//! Request signing.
+//!
+//! [`sign_request`] and [`verify_request`] apply TC3-HMAC-SHA256 to plain [`http::Request`]s, for
+//! proxies and gateways that talk to Tencent Cloud without going through [`Client`].
+//!
+//! [`Client`]: crate::Client
+
+use crate::{Error, auth::Credentials, util::canonical_query_string};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use chrono::{TimeZone, Utc};
 use hmac::{Hmac, Mac};
-use http::{HeaderMap, HeaderValue, Method};
+use http::{HeaderMap, HeaderValue, Method, Request};
+use serde_json::Value;
+use sha1::Sha1;
 use sha2::{Digest, Sha256};
+use std::fmt;
 use thiserror::Error as ThisError;
 
 pub(crate) type HmacSha256 = Hmac<Sha256>;
+type HmacSha1 = Hmac<Sha1>;
+
+const TC3_ALGORITHM: &str = "TC3-HMAC-SHA256";
+const CONTENT_TYPE: &str = "application/json; charset=utf-8";
+const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+const CONTENT_TYPE_HEADER: &str = "content-type";
+
+/// How requests are signed.
+///
+/// `HmacSha1` and `HmacSha256` select the legacy (v1) scheme, where the action, version,
+/// region, `Timestamp`, `Nonce` and `Signature` travel as query parameters (GET) or form fields
+/// (POST) and the JSON payload is flattened into `Key.0.SubKey` parameters.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum SignatureMethod {
+    #[default]
+    Tc3HmacSha256,
+    HmacSha1,
+    HmacSha256,
+}
+
+impl SignatureMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SignatureMethod::Tc3HmacSha256 => "TC3-HMAC-SHA256",
+            SignatureMethod::HmacSha1 => "HmacSHA1",
+            SignatureMethod::HmacSha256 => "HmacSHA256",
+        }
+    }
+
+    pub(crate) fn is_legacy(self) -> bool {
+        self != SignatureMethod::Tc3HmacSha256
+    }
+}
+
+impl fmt::Display for SignatureMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 #[derive(Debug, ThisError)]
 #[non_exhaustive]
@@ -15,6 +66,12 @@ pub(crate) enum SigningError {
 
     #[error("timestamp {0} is out of range for chrono")]
     InvalidTimestamp(i64),
+
+    #[error("missing or non-ASCII `{0}` header")]
+    MissingHeader(String),
+
+    #[error("Authorization header is not a TC3-HMAC-SHA256 signature")]
+    MalformedAuthorization,
 }
 
 pub(crate) struct SigningInput<'a> {
@@ -26,36 +83,90 @@ pub(crate) struct SigningInput<'a> {
     pub(crate) region: Option<&'a str>,
     pub(crate) action: &'a str,
     pub(crate) version: &'a str,
-    pub(crate) payload: &'a str,
+    pub(crate) payload: &'a [u8],
+    pub(crate) content_type: &'a str,
     pub(crate) timestamp: i64,
 }
 
-pub(crate) fn build_tc3_headers(auth: &Auth, input: &SigningInput<'_>) -> Result<HeaderMap, Error> {
-    let Auth::Tc3(credentials) = auth else {
-        return Err(Error::signing(Box::new(SigningError::MissingCredentials)));
-    };
+/// Content type Tencent Cloud expects for `method`: form encoding for `GET`/`HEAD`, JSON otherwise.
+pub(crate) fn content_type_for(method: &Method) -> &'static str {
+    if *method == Method::GET || *method == Method::HEAD {
+        FORM_CONTENT_TYPE
+    } else {
+        CONTENT_TYPE
+    }
+}
+
+pub(crate) fn build_tc3_headers(
+    credentials: &Credentials,
+    input: &SigningInput<'_>,
+) -> Result<HeaderMap, Error> {
+    let signed_headers = "content-type;host;x-tc-action";
+    let canonical_headers = canonical_headers([
+        ("content-type", input.content_type),
+        ("host", input.host),
+        ("x-tc-action", input.action),
+    ]);
+
+    let (credential_scope, signature) = tc3_signature(
+        credentials.secret_key(),
+        input,
+        &canonical_headers,
+        signed_headers,
+    )?;
+    let authorization = format!(
+        "{TC3_ALGORITHM} Credential={}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        credentials.secret_id(),
+    );
 
+    let mut headers = common_headers(input)?;
+    headers.insert(
+        "Authorization",
+        HeaderValue::from_str(&authorization).map_err(|source| Error::signing(Box::new(source)))?,
+    );
+    if let Some(token) = credentials.token() {
+        headers.insert(
+            "X-TC-Token",
+            HeaderValue::from_str(token).map_err(|source| Error::signing(Box::new(source)))?,
+        );
+    }
+
+    Ok(headers)
+}
+
+/// `name:value\n` per signed header, lowercased and trimmed as TC3 requires. Signing and
+/// verification both build the canonical headers here.
+fn canonical_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    headers
+        .into_iter()
+        .map(|(name, value)| {
+            format!(
+                "{}:{}\n",
+                name.to_ascii_lowercase(),
+                value.trim().to_ascii_lowercase()
+            )
+        })
+        .collect()
+}
+
+/// Returns the credential scope and hex signature for `input`.
+fn tc3_signature(
+    secret_key: &str,
+    input: &SigningInput<'_>,
+    canonical_headers: &str,
+    signed_headers: &str,
+) -> Result<(String, String), Error> {
     let SigningInput {
         method,
         service,
-        host,
         path,
         canonical_query,
-        region,
-        action,
-        version,
         payload,
         timestamp,
+        ..
     } = input;
 
-    let algorithm = "TC3-HMAC-SHA256";
-    let content_type = "application/json; charset=utf-8";
-    let lower_action = action.to_ascii_lowercase();
-    let canonical_headers =
-        format!("content-type:{content_type}\nhost:{host}\nx-tc-action:{lower_action}\n");
-    let signed_headers = "content-type;host;x-tc-action";
-
-    let hashed_payload = sha256_hex(payload.as_bytes());
+    let hashed_payload = sha256_hex(payload);
     let canonical_request = format!(
         "{}\n{}\n{}\n{}\n{}\n{}",
         method.as_str(),
@@ -76,9 +187,9 @@ pub(crate) fn build_tc3_headers(auth: &Auth, input: &SigningInput<'_>) -> Result
     let hashed_canonical_request = sha256_hex(canonical_request.as_bytes());
     let credential_scope = format!("{date}/{service}/tc3_request");
     let string_to_sign =
-        format!("{algorithm}\n{timestamp}\n{credential_scope}\n{hashed_canonical_request}");
+        format!("{TC3_ALGORITHM}\n{timestamp}\n{credential_scope}\n{hashed_canonical_request}");
 
-    let secret_date = hmac_sha256(format!("TC3{}", credentials.secret_key()).as_bytes(), &date)?;
+    let secret_date = hmac_sha256(format!("TC3{secret_key}").as_bytes(), &date)?;
     let secret_service = hmac_sha256(&secret_date, service)?;
     let secret_signing = hmac_sha256(&secret_service, "tc3_request")?;
 
@@ -87,16 +198,338 @@ pub(crate) fn build_tc3_headers(auth: &Auth, input: &SigningInput<'_>) -> Result
     mac.update(string_to_sign.as_bytes());
     let signature = format!("{:x}", mac.finalize().into_bytes());
 
-    let authorization = format!(
-        "{algorithm} Credential={}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}",
-        credentials.secret_id(),
-    );
+    Ok((credential_scope, signature))
+}
+
+/// Service, action, version and region of a call signed with [`sign_request`].
+#[derive(Debug, Clone)]
+pub struct SigningParams {
+    service: String,
+    action: String,
+    version: String,
+    region: Option<String>,
+    timestamp: Option<i64>,
+}
+
+impl SigningParams {
+    pub fn new(
+        service: impl Into<String>,
+        action: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            service: service.into(),
+            action: action.into(),
+            version: version.into(),
+            region: None,
+            timestamp: None,
+        }
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Unix time to sign with; defaults to the current time.
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
+/// Signs `request` with TC3-HMAC-SHA256 and returns the headers to send with it.
+///
+/// The method, host, path, query string, body and `Content-Type` are taken from `request`;
+/// without a `Content-Type`, `GET` requests are signed as `application/x-www-form-urlencoded` and
+/// others as `application/json; charset=utf-8`. The returned `Host`, `Content-Type`, `X-TC-*` and
+/// `Authorization` headers are the ones that were signed and must replace any the request
+/// already has.
+pub fn sign_request<B: AsRef<[u8]>>(
+    request: &Request<B>,
+    credentials: &Credentials,
+    params: &SigningParams,
+) -> Result<HeaderMap, Error> {
+    let host = request_host(request)?;
+    let content_type = match request.headers().get(CONTENT_TYPE_HEADER) {
+        Some(_) => header_str(request.headers(), CONTENT_TYPE_HEADER)?,
+        None => content_type_for(request.method()),
+    };
+    let timestamp = params.timestamp.unwrap_or_else(|| Utc::now().timestamp());
+    let input = SigningInput {
+        method: request.method(),
+        service: &params.service,
+        host,
+        path: request_path(request),
+        canonical_query: request.uri().query().unwrap_or(""),
+        region: params.region.as_deref(),
+        action: &params.action,
+        version: &params.version,
+        payload: request.body().as_ref(),
+        content_type,
+        timestamp,
+    };
+    build_tc3_headers(credentials, &input)
+}
+
+/// Checks the TC3 `Authorization` header of `request` against `secret_key`.
+///
+/// Returns `Ok(false)` when the signature does not match, and an error when the request lacks
+/// the headers of a TC3-signed request. `X-TC-Timestamp` is not compared with the current time.
+pub fn verify_request<B: AsRef<[u8]>>(
+    request: &Request<B>,
+    secret_key: &str,
+) -> Result<bool, Error> {
+    let authorization = header_str(request.headers(), "authorization")?;
+    let fields = authorization
+        .strip_prefix(TC3_ALGORITHM)
+        .filter(|rest| rest.starts_with(' '))
+        .ok_or(SigningError::MalformedAuthorization)
+        .map_err(|source| Error::signing(Box::new(source)))?;
+
+    let mut credential = None;
+    let mut signed_headers = None;
+    let mut signature = None;
+    for field in fields.split(',') {
+        match field.trim().split_once('=') {
+            Some(("Credential", value)) => credential = Some(value),
+            Some(("SignedHeaders", value)) => signed_headers = Some(value),
+            Some(("Signature", value)) => signature = Some(value),
+            _ => {}
+        }
+    }
+    let (Some(credential), Some(signed_headers), Some(signature)) =
+        (credential, signed_headers, signature)
+    else {
+        return Err(Error::signing(Box::new(
+            SigningError::MalformedAuthorization,
+        )));
+    };
+    let Some((_secret_id, scope)) = credential.split_once('/') else {
+        return Err(Error::signing(Box::new(
+            SigningError::MalformedAuthorization,
+        )));
+    };
+    let service = match scope.split('/').collect::<Vec<_>>()[..] {
+        [_date, service, "tc3_request"] => service,
+        _ => {
+            return Err(Error::signing(Box::new(
+                SigningError::MalformedAuthorization,
+            )));
+        }
+    };
+
+    let timestamp = header_str(request.headers(), "x-tc-timestamp")?
+        .parse::<i64>()
+        .map_err(|source| Error::signing(Box::new(source)))?;
+    let host = request_host(request)?;
+
+    let signed_values = signed_headers
+        .split(';')
+        .map(|name| match (name, request.headers().get(name)) {
+            ("host", None) => Ok((name, host)),
+            _ => header_str(request.headers(), name).map(|value| (name, value)),
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let canonical_headers = canonical_headers(signed_values);
+
+    let input = SigningInput {
+        method: request.method(),
+        service,
+        host,
+        path: request_path(request),
+        canonical_query: request.uri().query().unwrap_or(""),
+        region: None,
+        action: "",
+        version: "",
+        payload: request.body().as_ref(),
+        content_type: "",
+        timestamp,
+    };
+    let (expected_scope, expected) =
+        tc3_signature(secret_key, &input, &canonical_headers, signed_headers)?;
+
+    Ok(expected_scope == scope && constant_time_eq(expected.as_bytes(), signature.as_bytes()))
+}
+
+fn request_host<B>(request: &Request<B>) -> Result<&str, Error> {
+    match request.uri().authority() {
+        Some(authority) => Ok(authority.as_str()),
+        None => header_str(request.headers(), "host"),
+    }
+}
+
+fn request_path<B>(request: &Request<B>) -> &str {
+    match request.uri().path() {
+        "" => "/",
+        path => path,
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Result<&'a str, Error> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| SigningError::MissingHeader(name.to_string()))
+        .map_err(|source| Error::signing(Box::new(source)))
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .fold(0u8, |diff, (left, right)| diff | (left ^ right))
+            == 0
+}
+
+/// Headers for actions that are called without a signature, e.g. `sts:AssumeRoleWithWebIdentity`.
+pub(crate) fn build_unsigned_headers(input: &SigningInput<'_>) -> Result<HeaderMap, Error> {
+    let mut headers = common_headers(input)?;
+    headers.insert("Authorization", HeaderValue::from_static("SKIP"));
+    Ok(headers)
+}
+
+/// Signs `params` with the legacy (v1) scheme.
+///
+/// Returns the headers to send and all parameters, `Signature` included, percent-encoded as a
+/// query string; it becomes the URL query for GET requests and the form body otherwise.
+pub(crate) fn build_v1_request(
+    credentials: &Credentials,
+    input: &SigningInput<'_>,
+    params: &[(String, String)],
+    signature_method: SignatureMethod,
+    nonce: u32,
+) -> Result<(HeaderMap, String), Error> {
+    let SigningInput {
+        method,
+        host,
+        path,
+        region,
+        action,
+        version,
+        timestamp,
+        ..
+    } = input;
+
+    let mut params = params.to_vec();
+    params.push(("Action".to_string(), action.to_string()));
+    params.push(("Version".to_string(), version.to_string()));
+    params.push(("Timestamp".to_string(), timestamp.to_string()));
+    params.push(("Nonce".to_string(), nonce.to_string()));
+    params.push(("SecretId".to_string(), credentials.secret_id().to_string()));
+    params.push((
+        "SignatureMethod".to_string(),
+        signature_method.as_str().to_string(),
+    ));
+    if let Some(region) = region {
+        params.push(("Region".to_string(), region.to_string()));
+    }
+    if let Some(token) = credentials.token() {
+        params.push(("Token".to_string(), token.to_string()));
+    }
+    params.sort();
+
+    let string_to_sign = v1_string_to_sign(method, host, path, &params);
+    let signature = v1_signature(credentials.secret_key(), signature_method, &string_to_sign)?;
+    params.push(("Signature".to_string(), signature));
 
     let mut headers = HeaderMap::new();
+    if *method != Method::GET && *method != Method::HEAD {
+        headers.insert("Content-Type", HeaderValue::from_static(FORM_CONTENT_TYPE));
+    }
     headers.insert(
-        "Authorization",
-        HeaderValue::from_str(&authorization).map_err(|source| Error::signing(Box::new(source)))?,
+        "Host",
+        HeaderValue::from_str(host).map_err(|source| Error::signing(Box::new(source)))?,
     );
+
+    Ok((headers, canonical_query_string(&params)))
+}
+
+/// Method, host, path and the sorted parameters, unencoded, e.g.
+/// `GETcvm.tencentcloudapi.com/?Action=DescribeInstances&...`.
+fn v1_string_to_sign(
+    method: &Method,
+    host: &str,
+    path: &str,
+    params: &[(String, String)],
+) -> String {
+    let joined = params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}{host}{path}?{joined}", method.as_str())
+}
+
+fn v1_signature(
+    secret_key: &str,
+    signature_method: SignatureMethod,
+    string_to_sign: &str,
+) -> Result<String, Error> {
+    let secret_key = secret_key.as_bytes();
+    Ok(match signature_method {
+        SignatureMethod::HmacSha1 => {
+            let mut mac = HmacSha1::new_from_slice(secret_key)
+                .map_err(|source| Error::signing(Box::new(source)))?;
+            mac.update(string_to_sign.as_bytes());
+            BASE64.encode(mac.finalize().into_bytes())
+        }
+        _ => BASE64.encode(hmac_sha256(secret_key, string_to_sign)?),
+    })
+}
+
+/// Flattens a JSON payload into v1 parameters: `{"Filters":[{"Name":"zone"}]}` becomes
+/// `Filters.0.Name=zone`. Nulls are dropped.
+pub(crate) fn flatten_params(payload: Option<&Value>) -> Result<Vec<(String, String)>, Error> {
+    let mut params = Vec::new();
+    match payload {
+        None => {}
+        Some(Value::Object(map)) => {
+            for (key, value) in map {
+                flatten_value(key.clone(), value, &mut params);
+            }
+        }
+        Some(_) => {
+            return Err(Error::invalid_config(
+                "legacy signature methods require a JSON object payload",
+            ));
+        }
+    }
+    Ok(params)
+}
+
+fn flatten_value(prefix: String, value: &Value, params: &mut Vec<(String, String)>) {
+    match value {
+        Value::Null => {}
+        Value::Bool(value) => params.push((prefix, value.to_string())),
+        Value::Number(value) => params.push((prefix, value.to_string())),
+        Value::String(value) => params.push((prefix, value.clone())),
+        Value::Array(values) => {
+            for (idx, value) in values.iter().enumerate() {
+                flatten_value(format!("{prefix}.{idx}"), value, params);
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map {
+                flatten_value(format!("{prefix}.{key}"), value, params);
+            }
+        }
+    }
+}
+
+fn common_headers(input: &SigningInput<'_>) -> Result<HeaderMap, Error> {
+    let SigningInput {
+        host,
+        region,
+        action,
+        version,
+        content_type,
+        timestamp,
+        ..
+    } = input;
+
+    let mut headers = HeaderMap::new();
     headers.insert(
         "Content-Type",
         HeaderValue::from_str(content_type).map_err(|source| Error::signing(Box::new(source)))?,
@@ -124,12 +557,6 @@ pub(crate) fn build_tc3_headers(auth: &Auth, input: &SigningInput<'_>) -> Result
             HeaderValue::from_str(region).map_err(|source| Error::signing(Box::new(source)))?,
         );
     }
-    if let Some(token) = credentials.token() {
-        headers.insert(
-            "X-TC-Token",
-            HeaderValue::from_str(token).map_err(|source| Error::signing(Box::new(source)))?,
-        );
-    }
 
     Ok(headers)
 }
@@ -155,7 +582,7 @@ mod tests {
 
     #[test]
     fn tc3_authorization_snapshot_matches_reference() {
-        let auth = Auth::tc3(
+        let credentials = Credentials::new(
             "AKIDz8krbsJ5yKBZQpn74WFkmLPx3xxxx",
             "Gu5t9xGARNpq86cd98joQYCN3Cozxxxx",
         );
@@ -168,7 +595,7 @@ mod tests {
         })
         .to_string();
         let headers = build_tc3_headers(
-            &auth,
+            &credentials,
             &SigningInput {
                 method: &method,
                 service: "cvm",
@@ -178,7 +605,8 @@ mod tests {
                 region: Some("ap-guangzhou"),
                 action: "DescribeInstances",
                 version: "2017-03-12",
-                payload: &payload,
+                payload: payload.as_bytes(),
+                content_type: CONTENT_TYPE,
                 timestamp: 1551113065,
             },
         )
@@ -194,4 +622,252 @@ mod tests {
             "TC3-HMAC-SHA256 Credential=AKIDz8krbsJ5yKBZQpn74WFkmLPx3xxxx/2019-02-25/cvm/tc3_request, SignedHeaders=content-type;host;x-tc-action, Signature=fb562f0e44f0c7f0afa9eff2998c6fc41e053d0efa3741b068332b545afdb587"
         );
     }
+
+    fn describe_instances_request() -> Request<Vec<u8>> {
+        let payload = json!({
+            "Limit": 1,
+            "Filters": [
+                { "Name": "zone", "Values": ["ap-guangzhou-1"] }
+            ]
+        })
+        .to_string();
+        Request::post("https://cvm.tencentcloudapi.com/")
+            .body(payload.into_bytes())
+            .unwrap()
+    }
+
+    fn describe_instances_params() -> SigningParams {
+        SigningParams::new("cvm", "DescribeInstances", "2017-03-12")
+            .region("ap-guangzhou")
+            .timestamp(1551113065)
+    }
+
+    #[test]
+    fn sign_request_matches_reference() {
+        let credentials = Credentials::new(
+            "AKIDz8krbsJ5yKBZQpn74WFkmLPx3xxxx",
+            "Gu5t9xGARNpq86cd98joQYCN3Cozxxxx",
+        );
+        let headers = sign_request(
+            &describe_instances_request(),
+            &credentials,
+            &describe_instances_params(),
+        )
+        .expect("sign request");
+
+        assert!(headers["Authorization"].to_str().unwrap().ends_with(
+            "Signature=fb562f0e44f0c7f0afa9eff2998c6fc41e053d0efa3741b068332b545afdb587"
+        ));
+        assert_eq!(headers["Host"], "cvm.tencentcloudapi.com");
+        assert_eq!(headers["X-TC-Timestamp"], "1551113065");
+    }
+
+    #[test]
+    fn verify_request_accepts_signed_requests_only() {
+        let credentials = Credentials::new("AKIDtest", "secret-key");
+        let mut request = describe_instances_request();
+        let headers = sign_request(&request, &credentials, &describe_instances_params()).unwrap();
+        request.headers_mut().extend(headers);
+
+        assert!(verify_request(&request, "secret-key").unwrap());
+        assert!(!verify_request(&request, "other-key").unwrap());
+
+        request.body_mut().push(b' ');
+        assert!(!verify_request(&request, "secret-key").unwrap());
+
+        request.headers_mut().remove("X-TC-Timestamp");
+        assert!(verify_request(&request, "secret-key").is_err());
+    }
+
+    #[test]
+    fn verify_request_matches_signing_for_mixed_case_hosts() {
+        let credentials = Credentials::new("AKIDtest", "secret-key");
+        let mut request = Request::post("https://CVM.TencentCloudAPI.com/")
+            .body(b"{}".to_vec())
+            .unwrap();
+        let headers = sign_request(&request, &credentials, &describe_instances_params()).unwrap();
+        request.headers_mut().extend(headers);
+
+        assert!(verify_request(&request, "secret-key").unwrap());
+    }
+
+    #[test]
+    fn sign_request_signs_the_request_content_type() {
+        let credentials = Credentials::new("AKIDtest", "secret-key");
+        let mut request = Request::get("https://cvm.tencentcloudapi.com/?Limit=1")
+            .body(Vec::new())
+            .unwrap();
+        let headers = sign_request(&request, &credentials, &describe_instances_params()).unwrap();
+        assert_eq!(headers["Content-Type"], FORM_CONTENT_TYPE);
+        request.headers_mut().extend(headers);
+        assert!(verify_request(&request, "secret-key").unwrap());
+
+        let request = Request::post("https://cvm.tencentcloudapi.com/")
+            .header("Content-Type", "application/json")
+            .body(b"{}".to_vec())
+            .unwrap();
+        let headers = sign_request(&request, &credentials, &describe_instances_params()).unwrap();
+        assert_eq!(headers["Content-Type"], "application/json");
+    }
+
+    #[test]
+    fn unsigned_headers_skip_authorization() {
+        let method = Method::POST;
+        let headers = build_unsigned_headers(&SigningInput {
+            method: &method,
+            service: "sts",
+            host: "sts.tencentcloudapi.com",
+            path: "/",
+            canonical_query: "",
+            region: Some("ap-guangzhou"),
+            action: "AssumeRoleWithWebIdentity",
+            version: "2018-08-13",
+            payload: b"{}",
+            content_type: CONTENT_TYPE,
+            timestamp: 1551113065,
+        })
+        .expect("build headers");
+
+        assert_eq!(headers["Authorization"], "SKIP");
+        assert_eq!(headers["X-TC-Action"], "AssumeRoleWithWebIdentity");
+        assert_eq!(headers["X-TC-Region"], "ap-guangzhou");
+        assert!(headers.get("X-TC-Token").is_none());
+    }
+
+    fn v1_params() -> Vec<(String, String)> {
+        flatten_params(Some(&json!({
+            "InstanceIds": ["ins-09dx96dg"],
+            "Limit": 20,
+            "Offset": 0
+        })))
+        .expect("flatten payload")
+    }
+
+    fn v1_input(method: &Method) -> SigningInput<'_> {
+        SigningInput {
+            method,
+            service: "cvm",
+            host: "cvm.tencentcloudapi.com",
+            path: "/",
+            canonical_query: "",
+            region: Some("ap-guangzhou"),
+            action: "DescribeInstances",
+            version: "2017-03-12",
+            payload: b"",
+            content_type: content_type_for(method),
+            timestamp: 1465185768,
+        }
+    }
+
+    #[test]
+    fn hmac_sha1_signature_matches_documented_example() {
+        // The v1 signing guide's example omits SignatureMethod, which defaults to HmacSHA1;
+        // `build_v1_request` always sends it, so the documented string is rebuilt here.
+        let mut params = v1_params();
+        params.extend(
+            [
+                ("Action", "DescribeInstances"),
+                ("Nonce", "11886"),
+                ("Region", "ap-guangzhou"),
+                ("SecretId", "AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******"),
+                ("Timestamp", "1465185768"),
+                ("Version", "2017-03-12"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        params.sort();
+
+        let string_to_sign =
+            v1_string_to_sign(&Method::GET, "cvm.tencentcloudapi.com", "/", &params);
+        assert_eq!(
+            string_to_sign,
+            "GETcvm.tencentcloudapi.com/?Action=DescribeInstances&InstanceIds.0=ins-09dx96dg\
+             &Limit=20&Nonce=11886&Offset=0&Region=ap-guangzhou\
+             &SecretId=AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******&Timestamp=1465185768&Version=2017-03-12"
+        );
+        assert_eq!(
+            v1_signature(
+                "Gu5t9xGARNpq86cd98joQYCN3*******",
+                SignatureMethod::HmacSha1,
+                &string_to_sign
+            )
+            .unwrap(),
+            "zmmjn35mikh6pM3V7sUEuX4wyYM="
+        );
+    }
+
+    #[test]
+    fn hmac_sha1_signature_snapshot() {
+        let credentials = Credentials::new(
+            "AKIDz8krbsJ5yKBZQpn74WFkmLPx3xxxx",
+            "Gu5t9xGARNpq86cd98joQYCN3Cozxxxx",
+        );
+        let method = Method::GET;
+        let (headers, query) = build_v1_request(
+            &credentials,
+            &v1_input(&method),
+            &v1_params(),
+            SignatureMethod::HmacSha1,
+            11886,
+        )
+        .expect("sign request");
+
+        assert_eq!(
+            query,
+            "Action=DescribeInstances&InstanceIds.0=ins-09dx96dg&Limit=20&Nonce=11886&Offset=0\
+             &Region=ap-guangzhou&SecretId=AKIDz8krbsJ5yKBZQpn74WFkmLPx3xxxx\
+             &Signature=AQMq89cytOAvVwNWMWu5POCro3c%3D&SignatureMethod=HmacSHA1\
+             &Timestamp=1465185768&Version=2017-03-12"
+        );
+        assert_eq!(headers["Host"], "cvm.tencentcloudapi.com");
+        assert!(headers.get("Content-Type").is_none());
+    }
+
+    #[test]
+    fn hmac_sha256_signature_snapshot() {
+        let credentials = Credentials::new(
+            "AKIDz8krbsJ5yKBZQpn74WFkmLPx3xxxx",
+            "Gu5t9xGARNpq86cd98joQYCN3Cozxxxx",
+        )
+        .with_token("tmp-token");
+        let method = Method::POST;
+        let (headers, body) = build_v1_request(
+            &credentials,
+            &v1_input(&method),
+            &v1_params(),
+            SignatureMethod::HmacSha256,
+            11886,
+        )
+        .expect("sign request");
+
+        assert!(body.contains("&Signature=wFVDCmhYvAa38kqXye3gzCFvwnDbsR7eojvXxnorcQ0%3D&"));
+        assert!(body.contains("&Token=tmp-token&"));
+        assert_eq!(headers["Content-Type"], FORM_CONTENT_TYPE);
+    }
+
+    #[test]
+    fn flatten_params_expands_nested_payloads() {
+        let mut params = flatten_params(Some(&json!({
+            "Filters": [{ "Name": "zone", "Values": ["ap-guangzhou-1", "ap-guangzhou-2"] }],
+            "DryRun": false,
+            "Marker": null
+        })))
+        .unwrap();
+        params.sort();
+
+        let params: Vec<_> = params
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+        assert_eq!(
+            params,
+            [
+                "DryRun=false",
+                "Filters.0.Name=zone",
+                "Filters.0.Values.0=ap-guangzhou-1",
+                "Filters.0.Values.1=ap-guangzhou-2",
+            ]
+        );
+        assert!(flatten_params(Some(&json!([1]))).is_err());
+    }
 }
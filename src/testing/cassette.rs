@@ -0,0 +1,368 @@
+use super::service_and_action;
+use crate::{
+    Error,
+    error::ErrorKind,
+    transport::{TransportRequest, TransportResponse},
+    util::redact_json_value,
+};
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+#[cfg(feature = "blocking")]
+use crate::transport::BlockingTransport;
+#[cfg(feature = "async")]
+use crate::transport::{AsyncTransport, TransportFuture};
+
+/// Unused interactions per service and action, next one last.
+type Queues = HashMap<(String, String), Vec<Interaction>>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    service: String,
+    action: String,
+    /// Redacted request body; informational, replay matches on service and action only.
+    request: Value,
+    /// `None` when the attempt failed before a response arrived; see `error`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status: Option<u16>,
+    /// Response headers such as `X-TC-RequestId` and `Date`, without cookies.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    /// Redacted response body, as JSON when it parses.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    response: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<TransportFailure>,
+}
+
+/// A transport error in place of a response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TransportFailure {
+    message: String,
+    /// Whether clients retried it, e.g. a connection reset or timeout.
+    retryable: bool,
+}
+
+/// Wraps a client's transport and appends every exchange to a cassette file.
+///
+/// Installed by `record_cassette` on the client builders. The file is rewritten after each
+/// response, so it is complete even when a test fails halfway.
+pub(crate) struct CassetteRecorder<T: ?Sized> {
+    inner: Arc<T>,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl<T: ?Sized> CassetteRecorder<T> {
+    pub(crate) fn new(inner: Arc<T>, path: PathBuf) -> Self {
+        Self {
+            inner,
+            path,
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    fn record(
+        &self,
+        request: &TransportRequest,
+        response: Result<TransportResponse, Error>,
+    ) -> Result<TransportResponse, Error> {
+        let (service, action) = service_and_action(request);
+        let mut interaction = Interaction {
+            service,
+            action,
+            request: request.body().map_or(Value::Null, redacted_request),
+            status: None,
+            headers: BTreeMap::new(),
+            response: Value::Null,
+            error: None,
+        };
+        match &response {
+            Ok(response) => {
+                interaction.status = Some(response.status.as_u16());
+                interaction.headers = recorded_headers(&response.headers);
+                interaction.response = redacted_response(&response.body);
+            }
+            Err(err) if err.kind() == ErrorKind::Transport => {
+                interaction.error = Some(TransportFailure {
+                    message: std::error::Error::source(err)
+                        .map_or_else(|| err.to_string(), ToString::to_string),
+                    retryable: err.is_retryable(),
+                });
+            }
+            Err(_) => return response,
+        }
+
+        let mut cassette = self.cassette.lock().unwrap_or_else(PoisonError::into_inner);
+        cassette.interactions.push(interaction);
+        write_cassette(&self.path, &cassette)?;
+        response
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for CassetteRecorder<dyn AsyncTransport> {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let response = self.inner.send(request.clone()).await;
+            self.record(&request, response)
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for CassetteRecorder<dyn BlockingTransport> {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let response = self.inner.send(request.clone());
+        self.record(&request, response)
+    }
+}
+
+/// Transport that answers requests from a cassette written by `record_cassette`.
+///
+/// Interactions are replayed per service and action in recorded order; once they are used
+/// up, the last one keeps answering. Requests the cassette never saw fail with a
+/// non-retryable `Transport` error.
+#[derive(Debug, Clone)]
+pub struct CassettePlayer {
+    interactions: Arc<Mutex<Queues>>,
+}
+
+impl CassettePlayer {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let contents = fs::read(path).map_err(|source| {
+            Error::invalid_config_with_source(
+                format!("failed to read cassette {}", path.display()),
+                source,
+            )
+        })?;
+        let cassette: Cassette = serde_json::from_slice(&contents).map_err(|source| {
+            Error::invalid_config_with_source(
+                format!("failed to parse cassette {}", path.display()),
+                source,
+            )
+        })?;
+
+        let mut interactions = Queues::new();
+        for interaction in cassette.interactions {
+            let key = (interaction.service.clone(), interaction.action.clone());
+            interactions.entry(key).or_default().push(interaction);
+        }
+        interactions.values_mut().for_each(|queue| queue.reverse());
+
+        Ok(Self {
+            interactions: Arc::new(Mutex::new(interactions)),
+        })
+    }
+
+    fn replay(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let key = service_and_action(&request);
+        let interaction = {
+            let mut interactions = self
+                .interactions
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            interactions
+                .get_mut(&key)
+                .and_then(|queue| match queue.len() {
+                    0 => None,
+                    1 => queue.last().cloned(),
+                    _ => queue.pop(),
+                })
+        };
+        let Some(interaction) = interaction else {
+            return Err(request.transport_error(io::Error::other(format!(
+                "cassette has no interaction for {}:{}",
+                key.0, key.1
+            ))));
+        };
+
+        if let Some(failure) = interaction.error {
+            let kind = if failure.retryable {
+                io::ErrorKind::ConnectionReset
+            } else {
+                io::ErrorKind::Other
+            };
+            return Err(request.transport_error(io::Error::new(kind, failure.message)));
+        }
+
+        let status = StatusCode::from_u16(interaction.status.unwrap_or_default())
+            .map_err(|source| request.transport_error(source))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &interaction.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|source| request.transport_error(source))?;
+            let value =
+                HeaderValue::from_str(value).map_err(|source| request.transport_error(source))?;
+            headers.append(name, value);
+        }
+        let body = match interaction.response {
+            Value::String(body) => body,
+            Value::Null => String::new(),
+            body => body.to_string(),
+        };
+        Ok(TransportResponse::new(status, headers, body))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for CassettePlayer {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(std::future::ready(self.replay(request)))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for CassettePlayer {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        self.replay(request)
+    }
+}
+
+/// JSON bodies are redacted as they are; form bodies (legacy signing) are stored as an object
+/// so that `SecretId`, `Signature` and `Token` are redacted too.
+fn redacted_request(body: &str) -> Value {
+    let mut value = serde_json::from_str(body).unwrap_or_else(|_| {
+        let params: Map<String, Value> = url::form_urlencoded::parse(body.as_bytes())
+            .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+            .collect();
+        Value::Object(params)
+    });
+    redact_json_value(&mut value);
+    value
+}
+
+fn recorded_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| *name != http::header::SET_COOKIE)
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect()
+}
+
+fn redacted_response(body: &str) -> Value {
+    match serde_json::from_str(body) {
+        Ok(mut value) => {
+            redact_json_value(&mut value);
+            value
+        }
+        Err(_) => Value::String(body.to_string()),
+    }
+}
+
+fn write_cassette(path: &Path, cassette: &Cassette) -> Result<(), Error> {
+    let write = || -> io::Result<()> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let mut contents = serde_json::to_vec_pretty(cassette)?;
+        contents.push(b'\n');
+        fs::write(path, contents)
+    };
+    write().map_err(|source| {
+        Error::invalid_config_with_source(
+            format!("failed to write cassette {}", path.display()),
+            source,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_bodies_are_redacted() {
+        let request = redacted_request(
+            "Action=DescribeInstances&SecretId=AKIDsecret&Signature=abc%3D&Token=tmp&Limit=1",
+        );
+        assert_eq!(request["Action"], "DescribeInstances");
+        assert_eq!(request["Limit"], "1");
+        assert_eq!(request["SecretId"], "[redacted]");
+        assert_eq!(request["Signature"], "[redacted]");
+        assert_eq!(request["Token"], "[redacted]");
+
+        let response = redacted_response(
+            r#"{"Response":{"Credentials":{"TmpSecretKey":"key","Token":"tmp"},"RequestId":"r"}}"#,
+        );
+        assert_eq!(
+            response["Response"]["Credentials"]["TmpSecretKey"],
+            "[redacted]"
+        );
+        assert_eq!(response["Response"]["Credentials"]["Token"], "[redacted]");
+        assert_eq!(response["Response"]["RequestId"], "r");
+
+        assert_eq!(redacted_response("boom"), Value::String("boom".to_string()));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn replays_recorded_headers_and_transport_errors() {
+        use crate::transport::BlockingTransport;
+        use std::time::Duration;
+
+        struct Scripted(Mutex<Vec<Result<TransportResponse, Error>>>);
+
+        impl BlockingTransport for Scripted {
+            fn send(&self, _request: TransportRequest) -> Result<TransportResponse, Error> {
+                self.0.lock().unwrap().remove(0)
+            }
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-TC-RequestId", HeaderValue::from_static("req-header"));
+        headers.insert(
+            "Date",
+            HeaderValue::from_static("Mon, 25 Feb 2019 16:44:25 GMT"),
+        );
+        headers.insert("Set-Cookie", HeaderValue::from_static("session=secret"));
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert("X-TC-Action", HeaderValue::from_static("DescribeInstances"));
+        let request = TransportRequest::new(
+            http::Method::POST,
+            url::Url::parse("https://cvm.tencentcloudapi.com/").unwrap(),
+            request_headers,
+            Some("{}".to_string()),
+            Duration::from_secs(1),
+        );
+        let reset = request.transport_error(io::Error::from(io::ErrorKind::ConnectionReset));
+        let inner: Arc<dyn BlockingTransport> = Arc::new(Scripted(Mutex::new(vec![
+            Err(reset),
+            Ok(TransportResponse::new(StatusCode::OK, headers, "{}")),
+        ])));
+
+        let path = std::env::temp_dir().join(format!(
+            "tencent-sdk-cassette-headers-{}.json",
+            std::process::id()
+        ));
+        let recorder = CassetteRecorder::new(inner, path.clone());
+        assert!(recorder.send(request.clone()).is_err());
+        recorder.send(request.clone()).unwrap();
+
+        let player = CassettePlayer::open(&path).unwrap();
+        let err = player.replay(request.clone()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Transport);
+        assert!(err.is_retryable());
+        let response = player.replay(request).unwrap();
+        assert_eq!(response.headers["x-tc-requestid"], "req-header");
+        assert_eq!(response.headers["date"], "Mon, 25 Feb 2019 16:44:25 GMT");
+        assert!(response.headers.get("set-cookie").is_none());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,229 @@
+use super::service_and_action;
+use crate::{
+    Error,
+    transport::{TransportRequest, TransportResponse},
+};
+use http::{HeaderMap, StatusCode};
+use serde_json::{Value, json};
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+#[cfg(feature = "blocking")]
+use crate::transport::BlockingTransport;
+#[cfg(feature = "async")]
+use crate::transport::{AsyncTransport, TransportFuture};
+
+const MOCK_REQUEST_ID: &str = "mock-request-id";
+
+/// Transport that answers requests from scripted replies, matched by service and action.
+///
+/// Clones share their script and recorded requests. Requests without a matching rule fail
+/// with a non-retryable `Transport` error naming the service and action.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    rules: Vec<Rule>,
+    requests: Vec<MockRequest>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    service: String,
+    action: String,
+    replies: VecDeque<Reply>,
+}
+
+#[derive(Debug, Clone)]
+enum Reply {
+    Response(Value),
+    ServiceError { code: String, message: String },
+    Status { status: StatusCode, body: String },
+    Transport(io::ErrorKind),
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule for `service`/`action` and returns it for scripting replies.
+    ///
+    /// Replies are used in the order they were added; the last one keeps answering once the
+    /// others are used up. When several rules match, the first one added wins.
+    pub fn on(&self, service: impl Into<String>, action: impl Into<String>) -> MockRule<'_> {
+        let mut state = self.lock();
+        state.rules.push(Rule {
+            service: service.into(),
+            action: action.into(),
+            replies: VecDeque::new(),
+        });
+        let index = state.rules.len() - 1;
+        MockRule { mock: self, index }
+    }
+
+    /// Every request received so far, oldest first.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Requests received for `service`/`action`.
+    pub fn requests_for(&self, service: &str, action: &str) -> Vec<MockRequest> {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|request| request.service == service && request.action == action)
+            .cloned()
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn answer(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let recorded = MockRequest::new(request);
+        let reply = {
+            let mut state = self.lock();
+            state.requests.push(recorded.clone());
+            state
+                .rules
+                .iter_mut()
+                .find(|rule| rule.service == recorded.service && rule.action == recorded.action)
+                .and_then(|rule| match rule.replies.len() {
+                    0 => None,
+                    1 => rule.replies.front().cloned(),
+                    _ => rule.replies.pop_front(),
+                })
+        };
+
+        let request = &recorded.request;
+        match reply {
+            None => Err(request.transport_error(io::Error::other(format!(
+                "MockTransport has no reply for {}:{}",
+                recorded.service, recorded.action
+            )))),
+            Some(Reply::Transport(kind)) => Err(request.transport_error(io::Error::from(kind))),
+            Some(Reply::Status { status, body }) => {
+                Ok(TransportResponse::new(status, HeaderMap::new(), body))
+            }
+            Some(Reply::Response(mut response)) => {
+                if let Some(object) = response.as_object_mut() {
+                    object
+                        .entry("RequestId")
+                        .or_insert_with(|| json!(MOCK_REQUEST_ID));
+                }
+                Ok(json_response(json!({ "Response": response })))
+            }
+            Some(Reply::ServiceError { code, message }) => Ok(json_response(json!({
+                "Response": {
+                    "Error": { "Code": code, "Message": message },
+                    "RequestId": MOCK_REQUEST_ID
+                }
+            }))),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for MockTransport {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(std::future::ready(self.answer(request)))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for MockTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        self.answer(request)
+    }
+}
+
+/// Replies scripted for one service/action, see [`MockTransport::on`].
+pub struct MockRule<'a> {
+    mock: &'a MockTransport,
+    index: usize,
+}
+
+impl MockRule<'_> {
+    /// Answers with HTTP 200 and `{"Response": response}`; a missing `RequestId` is filled in.
+    pub fn respond(self, response: Value) -> Self {
+        self.push(Reply::Response(response))
+    }
+
+    /// Answers with a Tencent Cloud service error such as `ResourceNotFound.InstanceNotExist`.
+    pub fn respond_error(self, code: impl Into<String>, message: impl Into<String>) -> Self {
+        self.push(Reply::ServiceError {
+            code: code.into(),
+            message: message.into(),
+        })
+    }
+
+    /// Answers with a non-2xx HTTP status and a raw body.
+    pub fn respond_status(self, status: StatusCode, body: impl Into<String>) -> Self {
+        self.push(Reply::Status {
+            status,
+            body: body.into(),
+        })
+    }
+
+    /// Fails before any response, as if the connection broke with `kind`.
+    ///
+    /// Connection and timeout kinds are retried by clients configured with `retry`.
+    pub fn fail(self, kind: io::ErrorKind) -> Self {
+        self.push(Reply::Transport(kind))
+    }
+
+    fn push(self, reply: Reply) -> Self {
+        self.mock.lock().rules[self.index].replies.push_back(reply);
+        self
+    }
+}
+
+/// A signed request received by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    service: String,
+    action: String,
+    request: TransportRequest,
+}
+
+impl MockRequest {
+    fn new(request: TransportRequest) -> Self {
+        let (service, action) = service_and_action(&request);
+        Self {
+            service,
+            action,
+            request,
+        }
+    }
+
+    /// Service from the TC3 credential scope, or the first label of the host.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// The request exactly as the client would have sent it.
+    pub fn request(&self) -> &TransportRequest {
+        &self.request
+    }
+
+    /// The JSON body, when there is one.
+    pub fn payload(&self) -> Option<Value> {
+        serde_json::from_str(self.request.body()?).ok()
+    }
+}
+
+fn json_response(body: Value) -> TransportResponse {
+    TransportResponse::new(StatusCode::OK, HeaderMap::new(), body.to_string())
+}
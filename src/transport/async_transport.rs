@@ -1,8 +1,8 @@
-use super::{TransportConfig, TransportResponse, host_with_port};
+use super::{
+    AsyncTransport, TransportConfig, TransportFuture, TransportRequest, TransportResponse,
+    host_with_port,
+};
 use crate::Error;
-use http::{HeaderMap, Method};
-use std::time::Duration;
-use url::Url;
 
 #[derive(Clone)]
 pub(crate) struct ReqwestAsyncTransport {
@@ -36,14 +36,14 @@ impl ReqwestAsyncTransport {
         Ok(Self { client })
     }
 
-    pub(crate) async fn send(
-        &self,
-        method: Method,
-        url: Url,
-        headers: HeaderMap,
-        body: Option<String>,
-        timeout: Duration,
-    ) -> Result<TransportResponse, Error> {
+    async fn execute(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let TransportRequest {
+            method,
+            url,
+            headers,
+            body,
+            timeout,
+        } = request;
         let host = host_with_port(&url);
         let path = url.path().to_string();
 
@@ -74,3 +74,9 @@ impl ReqwestAsyncTransport {
         })
     }
 }
+
+impl AsyncTransport for ReqwestAsyncTransport {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(self.execute(request))
+    }
+}
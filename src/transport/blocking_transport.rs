@@ -1,8 +1,7 @@
-use super::{TransportConfig, TransportResponse, host_with_port};
+use super::{
+    BlockingTransport, TransportConfig, TransportRequest, TransportResponse, host_with_port,
+};
 use crate::Error;
-use http::{HeaderMap, Method};
-use std::time::Duration;
-use url::Url;
 
 #[derive(Clone)]
 pub(crate) struct UreqBlockingTransport {
@@ -41,7 +40,7 @@ impl UreqBlockingTransport {
                     std::io::ErrorKind::InvalidInput,
                     "TLS is disabled for the blocking transport",
                 );
-                return Err(Error::invalid_request_with_source(
+                return Err(Error::invalid_config_with_source(
                     "danger_accept_invalid_certs requires `blocking-rustls` or `blocking-native-tls`",
                     Box::new(source),
                 ));
@@ -53,15 +52,17 @@ impl UreqBlockingTransport {
 
         Ok(Self { agent })
     }
+}
 
-    pub(crate) fn send(
-        &self,
-        method: Method,
-        url: Url,
-        headers: HeaderMap,
-        body: Option<String>,
-        timeout: Duration,
-    ) -> Result<TransportResponse, Error> {
+impl BlockingTransport for UreqBlockingTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let TransportRequest {
+            method,
+            url,
+            headers,
+            body,
+            timeout,
+        } = request;
         let host = host_with_port(&url);
         let path = url.path().to_string();
         let mut builder = http::Request::builder()
@@ -74,7 +75,7 @@ impl UreqBlockingTransport {
 
         if let Some(body) = body {
             let request = builder.body(body).map_err(|source| {
-                Error::invalid_request_with_source("failed to build request", Box::new(source))
+                Error::invalid_config_with_source("failed to build request", Box::new(source))
             })?;
 
             let request = self
@@ -101,7 +102,7 @@ impl UreqBlockingTransport {
         }
 
         let request = builder.body(()).map_err(|source| {
-            Error::invalid_request_with_source("failed to build request", Box::new(source))
+            Error::invalid_config_with_source("failed to build request", Box::new(source))
         })?;
 
         let request = self
@@ -1,19 +1,132 @@
-use http::{HeaderMap, StatusCode};
-use std::time::Duration;
+//! HTTP transports.
+//!
+//! The clients hand every signed request to a transport: reqwest for [`Client`] and ureq for
+//! [`BlockingClient`] unless another one is installed with `ClientBuilder::transport` /
+//! `BlockingClientBuilder::transport`. Implement [`AsyncTransport`] or [`BlockingTransport`]
+//! to route calls through your own HTTP stack or to answer them from a fake in tests.
+//!
+//! [`Client`]: crate::Client
+//! [`BlockingClient`]: crate::BlockingClient
+
+use crate::Error;
+use http::{HeaderMap, Method, StatusCode};
+use std::{error::Error as StdError, time::Duration};
 use url::Url;
 
-#[derive(Debug, Clone)]
-pub(crate) struct TransportResponse {
-    pub(crate) status: StatusCode,
-    pub(crate) headers: HeaderMap,
-    pub(crate) body: String,
-}
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
 
 #[cfg(feature = "async")]
 pub(crate) mod async_transport;
 #[cfg(feature = "blocking")]
 pub(crate) mod blocking_transport;
 
+/// Future returned by [`AsyncTransport::send`].
+#[cfg(feature = "async")]
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<TransportResponse, Error>> + Send + 'a>>;
+
+/// Sends signed requests for [`Client`](crate::Client).
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Send + Sync {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_>;
+}
+
+/// Sends signed requests for [`BlockingClient`](crate::BlockingClient).
+#[cfg(feature = "blocking")]
+pub trait BlockingTransport: Send + Sync {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error>;
+}
+
+/// A signed request, ready to be sent as is.
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    pub(crate) method: Method,
+    pub(crate) url: Url,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: Option<String>,
+    pub(crate) timeout: Duration,
+}
+
+impl TransportRequest {
+    pub(crate) fn new(
+        method: Method,
+        url: Url,
+        headers: HeaderMap,
+        body: Option<String>,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            method,
+            url,
+            headers,
+            body,
+            timeout,
+        }
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Headers are sent as is; changing signed ones (`Host`, `Content-Type`, `Authorization`)
+    /// makes the request fail authentication.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Time allowed for the whole exchange, from [`RequestOptions`] or the client default.
+    ///
+    /// [`RequestOptions`]: crate::client::RequestOptions
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Wraps a failure to send this request into a `Transport` error.
+    ///
+    /// The client retries it when `source` is an [`std::io::Error`] of a connection or timeout
+    /// kind.
+    pub fn transport_error(&self, source: impl Into<Box<dyn StdError + Send + Sync>>) -> Error {
+        Error::transport(
+            self.method.clone(),
+            host_with_port(&self.url),
+            self.url.path(),
+            source,
+        )
+    }
+}
+
+/// Status, headers and body of the response to a [`TransportRequest`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl TransportResponse {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers,
+            body: body.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct TransportConfig {
     pub(crate) user_agent: String,
@@ -73,7 +73,7 @@ impl Endpoint for UpdateDomainConfigRequest {
 
     fn payload(&self) -> Result<Option<Value>, Error> {
         let value = serde_json::to_value(self).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize UpdateDomainConfig request payload",
                 Box::new(source),
             )
@@ -1,6 +1,6 @@
 use crate::{
     Error,
-    client::endpoint::Endpoint,
+    client::{endpoint::Endpoint, paginator::Paginated},
     types::{Filter, ImageId, InstanceId, Region, RequestId, SecurityGroupId, SubnetId, VpcId},
 };
 use serde::{Deserialize, Serialize};
@@ -34,7 +34,7 @@ pub struct InstanceSummary {
     pub instance_state: Option<String>,
     #[serde(rename = "InstanceType")]
     pub instance_type: Option<String>,
-    #[serde(rename = "Cpu")]
+    #[serde(rename = "CPU", alias = "Cpu")]
     pub cpu: Option<u64>,
     #[serde(rename = "Memory")]
     pub memory: Option<u64>,
@@ -42,12 +42,16 @@ pub struct InstanceSummary {
     pub private_ip_addresses: Option<Vec<String>>,
     #[serde(rename = "PublicIpAddresses")]
     pub public_ip_addresses: Option<Vec<String>>,
-    #[serde(default)]
+    #[serde(rename = "Placement", default)]
     pub placement: Option<InstancePlacement>,
-    #[serde(default)]
+    #[serde(rename = "SystemDisk", default)]
     pub system_disk: Option<DiskSummary>,
-    #[serde(default)]
+    #[serde(rename = "DataDisks", default)]
     pub data_disks: Option<Vec<DiskSummary>>,
+    #[serde(rename = "LatestOperation", default)]
+    pub latest_operation: Option<String>,
+    #[serde(rename = "LatestOperationState", default)]
+    pub latest_operation_state: Option<String>,
     #[serde(flatten, default)]
     pub extra: HashMap<String, Value>,
 }
@@ -75,6 +79,8 @@ pub struct DiskSummary {
 #[derive(Serialize)]
 #[serde(rename_all = "PascalCase")]
 struct DescribeInstancesPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance_ids: Option<&'a [InstanceId]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     filters: Option<&'a [Filter]>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,6 +91,7 @@ struct DescribeInstancesPayload<'a> {
 
 pub struct DescribeInstancesRequest {
     region: Option<Region>,
+    instance_ids: Vec<InstanceId>,
     filters: Vec<Filter>,
     limit: Option<u32>,
     offset: Option<u32>,
@@ -100,6 +107,7 @@ impl DescribeInstancesRequest {
     pub fn new() -> Self {
         Self {
             region: None,
+            instance_ids: Vec::new(),
             filters: Vec::new(),
             limit: None,
             offset: None,
@@ -111,6 +119,12 @@ impl DescribeInstancesRequest {
         self
     }
 
+    /// Restricts the query to these instances; Tencent Cloud rejects `InstanceIds` combined with filters.
+    pub fn push_instance_id(mut self, id: impl Into<InstanceId>) -> Self {
+        self.instance_ids.push(id.into());
+        self
+    }
+
     pub fn push_filter(mut self, filter: Filter) -> Self {
         self.filters.push(filter);
         self
@@ -147,14 +161,16 @@ impl Endpoint for DescribeInstancesRequest {
     }
 
     fn payload(&self) -> Result<Option<Value>, Error> {
+        let instance_ids = (!self.instance_ids.is_empty()).then_some(self.instance_ids.as_slice());
         let filters = (!self.filters.is_empty()).then_some(self.filters.as_slice());
         let payload = DescribeInstancesPayload {
+            instance_ids,
             filters,
             limit: self.limit,
             offset: self.offset,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeInstances request payload",
                 Box::new(source),
             )
@@ -163,6 +179,27 @@ impl Endpoint for DescribeInstancesRequest {
     }
 }
 
+impl Paginated for DescribeInstancesRequest {
+    type Item = InstanceSummary;
+
+    fn page_offset(&self) -> u64 {
+        self.offset.map_or(0, u64::from)
+    }
+
+    fn page_limit(&self) -> Option<u64> {
+        self.limit.map(u64::from)
+    }
+
+    fn set_page(&mut self, offset: u64, limit: u64) {
+        self.offset = Some(u32::try_from(offset).unwrap_or(u32::MAX));
+        self.limit = Some(u32::try_from(limit).unwrap_or(u32::MAX));
+    }
+
+    fn into_page(output: Self::Output) -> (Vec<Self::Item>, Option<u64>) {
+        (output.response.instance_set, output.response.total_count)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GenericActionResponse {
     #[serde(rename = "Response")]
@@ -247,7 +284,7 @@ impl Endpoint for ResetInstancesPasswordRequest {
             force_stop: self.force_stop,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize ResetInstancesPassword request payload",
                 Box::new(source),
             )
@@ -314,7 +351,7 @@ impl Endpoint for DescribeInstanceVncUrlRequest {
             instance_id: &self.instance_id,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeInstanceVncUrl request payload",
                 Box::new(source),
             )
@@ -457,7 +494,7 @@ impl Endpoint for RunInstancesRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize RunInstances request payload",
                 Box::new(source),
             )
@@ -513,7 +550,7 @@ impl Endpoint for StartInstancesRequest {
             instance_ids: &self.instance_ids,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize StartInstances request payload",
                 Box::new(source),
             )
@@ -579,7 +616,7 @@ impl Endpoint for RebootInstancesRequest {
             force_reboot: self.force_reboot,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize RebootInstances request payload",
                 Box::new(source),
             )
@@ -645,7 +682,7 @@ impl Endpoint for StopInstancesRequest {
             stop_type: self.stop_type.as_deref(),
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize StopInstances request payload",
                 Box::new(source),
             )
@@ -706,7 +743,7 @@ impl Endpoint for ModifyInstancesProjectRequest {
             project_id: self.project_id,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize ModifyInstancesProject request payload",
                 Box::new(source),
             )
@@ -756,7 +793,7 @@ impl Endpoint for TerminateInstancesRequest {
             instance_ids: &self.instance_ids,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize TerminateInstances request payload",
                 Box::new(source),
             )
@@ -889,7 +926,7 @@ impl Endpoint for DescribeImagesRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeImages request payload",
                 Box::new(source),
             )
@@ -898,6 +935,27 @@ impl Endpoint for DescribeImagesRequest {
     }
 }
 
+impl Paginated for DescribeImagesRequest {
+    type Item = ImageSummary;
+
+    fn page_offset(&self) -> u64 {
+        self.offset.map_or(0, u64::from)
+    }
+
+    fn page_limit(&self) -> Option<u64> {
+        self.limit.map(u64::from)
+    }
+
+    fn set_page(&mut self, offset: u64, limit: u64) {
+        self.offset = Some(u32::try_from(offset).unwrap_or(u32::MAX));
+        self.limit = Some(u32::try_from(limit).unwrap_or(u32::MAX));
+    }
+
+    fn into_page(output: Self::Output) -> (Vec<Self::Item>, Option<u64>) {
+        (output.response.image_set, output.response.total_count)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -919,6 +977,16 @@ mod tests {
         assert_eq!(payload["Offset"], json!(0));
     }
 
+    #[test]
+    fn describe_instances_payload_includes_instance_ids() {
+        let request = DescribeInstancesRequest::new()
+            .push_instance_id("ins-1")
+            .push_instance_id("ins-2");
+
+        let payload = request.payload().unwrap().unwrap();
+        assert_eq!(payload, json!({ "InstanceIds": ["ins-1", "ins-2"] }));
+    }
+
     #[test]
     fn deserialize_generic_action_response() {
         let payload = r#"{
@@ -1067,4 +1135,43 @@ mod tests {
             &Value::String("extra".to_string())
         );
     }
+
+    #[test]
+    fn deserialize_instance_summary_decodes_documented_field_names() {
+        let payload = r#"{
+            "Response": {
+                "TotalCount": 1,
+                "InstanceSet": [{
+                    "InstanceId": "ins-1",
+                    "CPU": 2,
+                    "Placement": { "Zone": "ap-guangzhou-3", "ProjectId": 0 },
+                    "SystemDisk": { "DiskType": "CLOUD_PREMIUM", "DiskSize": 50 },
+                    "DataDisks": [{ "DiskType": "CLOUD_SSD", "DiskSize": 100 }]
+                }],
+                "RequestId": "req-xyz"
+            }
+        }"#;
+
+        let parsed: DescribeInstancesResponse = serde_json::from_str(payload).unwrap();
+        let instance = &parsed.response.instance_set[0];
+        assert_eq!(instance.cpu, Some(2));
+        assert_eq!(
+            instance.placement.as_ref().and_then(|p| p.zone.as_deref()),
+            Some("ap-guangzhou-3")
+        );
+        assert_eq!(
+            instance.system_disk.as_ref().and_then(|d| d.disk_size),
+            Some(50)
+        );
+        assert_eq!(
+            instance
+                .data_disks
+                .as_deref()
+                .map(|disks| disks[0].disk_type.as_deref()),
+            Some(Some("CLOUD_SSD"))
+        );
+        for key in ["CPU", "Placement", "SystemDisk", "DataDisks"] {
+            assert!(!instance.extra.contains_key(key), "{key} left in extra");
+        }
+    }
 }
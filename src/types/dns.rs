@@ -235,7 +235,7 @@ impl Endpoint for CreateTxtRecordRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize CreateRecord request payload",
                 Box::new(source),
             )
@@ -393,7 +393,7 @@ impl Endpoint for ModifyTxtRecordRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize ModifyRecord request payload",
                 Box::new(source),
             )
@@ -472,7 +472,7 @@ impl Endpoint for DeleteRecordRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DeleteRecord request payload",
                 Box::new(source),
             )
@@ -7,6 +7,7 @@ pub mod cvm;
 pub mod dns;
 mod newtypes;
 pub mod ssl;
+pub mod sts;
 pub mod tag;
 pub mod vpc;
 
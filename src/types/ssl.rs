@@ -254,7 +254,7 @@ impl Endpoint for ApplyCertificateRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize ApplyCertificate request payload",
                 Box::new(source),
             )
@@ -440,7 +440,7 @@ impl Endpoint for DescribeCertificateRequest {
             certificate_id: &self.certificate_id,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeCertificate request payload",
                 Box::new(source),
             )
@@ -507,7 +507,7 @@ impl Endpoint for DownloadCertificateRequest {
             certificate_id: &self.certificate_id,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DownloadCertificate request payload",
                 Box::new(source),
             )
@@ -758,7 +758,7 @@ impl Endpoint for UploadCertificateRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize UploadCertificate request payload",
                 Box::new(source),
             )
@@ -0,0 +1,400 @@
+use crate::{
+    Error,
+    auth::Credentials,
+    client::endpoint::Endpoint,
+    types::{Region, RequestId},
+    util::encode_query_component,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fmt,
+    time::{Duration, UNIX_EPOCH},
+};
+
+const STS_SERVICE: &str = "sts";
+const STS_VERSION: &str = "2018-08-13";
+
+/// Response shared by `AssumeRole`, `GetFederationToken` and `AssumeRoleWithWebIdentity`.
+#[derive(Debug, Deserialize)]
+pub struct TemporaryCredentialsResponse {
+    #[serde(rename = "Response")]
+    pub response: TemporaryCredentialsResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TemporaryCredentialsResult {
+    #[serde(rename = "Credentials")]
+    pub credentials: StsCredentials,
+    /// Expiry as a Unix timestamp in seconds.
+    #[serde(rename = "ExpiredTime")]
+    pub expired_time: u64,
+    #[serde(rename = "Expiration")]
+    pub expiration: Option<String>,
+    #[serde(rename = "RequestId")]
+    pub request_id: RequestId,
+}
+
+impl TemporaryCredentialsResult {
+    /// Converts the temporary key pair into [`Credentials`] expiring at `ExpiredTime`.
+    pub fn to_credentials(&self) -> Credentials {
+        Credentials::new(
+            self.credentials.tmp_secret_id.as_str(),
+            self.credentials.tmp_secret_key.as_str(),
+        )
+        .with_token(self.credentials.token.as_str())
+        .with_expiry(UNIX_EPOCH + Duration::from_secs(self.expired_time))
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct StsCredentials {
+    #[serde(rename = "TmpSecretId")]
+    pub tmp_secret_id: String,
+    #[serde(rename = "TmpSecretKey")]
+    pub tmp_secret_key: String,
+    #[serde(rename = "Token")]
+    pub token: String,
+}
+
+impl fmt::Debug for StsCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StsCredentials")
+            .field("tmp_secret_id", &"[redacted]")
+            .field("tmp_secret_key", &"[redacted]")
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AssumeRoleRequest {
+    region: Option<Region>,
+    role_arn: String,
+    role_session_name: String,
+    duration_seconds: Option<u64>,
+    policy: Option<String>,
+    external_id: Option<String>,
+}
+
+impl AssumeRoleRequest {
+    pub fn new(role_arn: impl Into<String>, role_session_name: impl Into<String>) -> Self {
+        Self {
+            region: None,
+            role_arn: role_arn.into(),
+            role_session_name: role_session_name.into(),
+            duration_seconds: None,
+            policy: None,
+            external_id: None,
+        }
+    }
+
+    pub fn region(mut self, region: impl Into<Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn duration_seconds(mut self, seconds: u64) -> Self {
+        self.duration_seconds = Some(seconds);
+        self
+    }
+
+    /// Inline policy (JSON) further restricting the role session; URL-encoded on send.
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRolePayload<'a> {
+    role_arn: &'a str,
+    role_session_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<&'a str>,
+}
+
+impl Endpoint for AssumeRoleRequest {
+    type Output = TemporaryCredentialsResponse;
+
+    fn service(&self) -> &'static str {
+        STS_SERVICE
+    }
+
+    fn action(&self) -> &'static str {
+        "AssumeRole"
+    }
+
+    fn version(&self) -> &'static str {
+        STS_VERSION
+    }
+
+    fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    fn payload(&self) -> Result<Option<Value>, Error> {
+        let payload = AssumeRolePayload {
+            role_arn: &self.role_arn,
+            role_session_name: &self.role_session_name,
+            duration_seconds: self.duration_seconds,
+            policy: self.policy.as_deref().map(encode_query_component),
+            external_id: self.external_id.as_deref(),
+        };
+        let value = serde_json::to_value(payload).map_err(|source| {
+            Error::invalid_config_with_source(
+                "failed to serialize AssumeRole request payload",
+                Box::new(source),
+            )
+        })?;
+        Ok(Some(value))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetFederationTokenRequest {
+    region: Option<Region>,
+    name: String,
+    policy: String,
+    duration_seconds: Option<u64>,
+}
+
+impl GetFederationTokenRequest {
+    /// `policy` is the JSON policy granted to the federated user; URL-encoded on send.
+    pub fn new(name: impl Into<String>, policy: impl Into<String>) -> Self {
+        Self {
+            region: None,
+            name: name.into(),
+            policy: policy.into(),
+            duration_seconds: None,
+        }
+    }
+
+    pub fn region(mut self, region: impl Into<Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn duration_seconds(mut self, seconds: u64) -> Self {
+        self.duration_seconds = Some(seconds);
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetFederationTokenPayload<'a> {
+    name: &'a str,
+    policy: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_seconds: Option<u64>,
+}
+
+impl Endpoint for GetFederationTokenRequest {
+    type Output = TemporaryCredentialsResponse;
+
+    fn service(&self) -> &'static str {
+        STS_SERVICE
+    }
+
+    fn action(&self) -> &'static str {
+        "GetFederationToken"
+    }
+
+    fn version(&self) -> &'static str {
+        STS_VERSION
+    }
+
+    fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    fn payload(&self) -> Result<Option<Value>, Error> {
+        let payload = GetFederationTokenPayload {
+            name: &self.name,
+            policy: encode_query_component(&self.policy),
+            duration_seconds: self.duration_seconds,
+        };
+        let value = serde_json::to_value(payload).map_err(|source| {
+            Error::invalid_config_with_source(
+                "failed to serialize GetFederationToken request payload",
+                Box::new(source),
+            )
+        })?;
+        Ok(Some(value))
+    }
+}
+
+#[derive(Clone)]
+pub struct AssumeRoleWithWebIdentityRequest {
+    region: Option<Region>,
+    provider_id: String,
+    web_identity_token: String,
+    role_arn: String,
+    role_session_name: String,
+    duration_seconds: Option<u64>,
+}
+
+impl fmt::Debug for AssumeRoleWithWebIdentityRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssumeRoleWithWebIdentityRequest")
+            .field("region", &self.region)
+            .field("provider_id", &self.provider_id)
+            .field("web_identity_token", &"[redacted]")
+            .field("role_arn", &self.role_arn)
+            .field("role_session_name", &self.role_session_name)
+            .field("duration_seconds", &self.duration_seconds)
+            .finish()
+    }
+}
+
+impl AssumeRoleWithWebIdentityRequest {
+    pub fn new(
+        provider_id: impl Into<String>,
+        web_identity_token: impl Into<String>,
+        role_arn: impl Into<String>,
+        role_session_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            region: None,
+            provider_id: provider_id.into(),
+            web_identity_token: web_identity_token.into(),
+            role_arn: role_arn.into(),
+            role_session_name: role_session_name.into(),
+            duration_seconds: None,
+        }
+    }
+
+    pub fn region(mut self, region: impl Into<Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn duration_seconds(mut self, seconds: u64) -> Self {
+        self.duration_seconds = Some(seconds);
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleWithWebIdentityPayload<'a> {
+    provider_id: &'a str,
+    web_identity_token: &'a str,
+    role_arn: &'a str,
+    role_session_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_seconds: Option<u64>,
+}
+
+impl Endpoint for AssumeRoleWithWebIdentityRequest {
+    type Output = TemporaryCredentialsResponse;
+
+    fn service(&self) -> &'static str {
+        STS_SERVICE
+    }
+
+    fn action(&self) -> &'static str {
+        "AssumeRoleWithWebIdentity"
+    }
+
+    fn requires_signing(&self) -> bool {
+        false
+    }
+
+    fn version(&self) -> &'static str {
+        STS_VERSION
+    }
+
+    fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    fn payload(&self) -> Result<Option<Value>, Error> {
+        let payload = AssumeRoleWithWebIdentityPayload {
+            provider_id: &self.provider_id,
+            web_identity_token: &self.web_identity_token,
+            role_arn: &self.role_arn,
+            role_session_name: &self.role_session_name,
+            duration_seconds: self.duration_seconds,
+        };
+        let value = serde_json::to_value(payload).map_err(|source| {
+            Error::invalid_config_with_source(
+                "failed to serialize AssumeRoleWithWebIdentity request payload",
+                Box::new(source),
+            )
+        })?;
+        Ok(Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn assume_role_payload_encodes_policy() {
+        let request = AssumeRoleRequest::new("qcs::cam::uin/100:roleName/ops", "deploy")
+            .duration_seconds(1800)
+            .policy(r#"{"version":"2.0"}"#);
+        let payload = request.payload().unwrap().unwrap();
+
+        assert_eq!(payload["RoleArn"], json!("qcs::cam::uin/100:roleName/ops"));
+        assert_eq!(payload["RoleSessionName"], json!("deploy"));
+        assert_eq!(payload["DurationSeconds"], json!(1800));
+        assert_eq!(payload["Policy"], json!("%7B%22version%22%3A%222.0%22%7D"));
+        assert!(payload.get("ExternalId").is_none());
+    }
+
+    #[test]
+    fn web_identity_request_debug_redacts_token() {
+        let request = AssumeRoleWithWebIdentityRequest::new(
+            "OIDC",
+            "eyJhbGciOi",
+            "qcs::cam::uin/100:roleName/ops",
+            "pod",
+        );
+        assert!(!format!("{request:?}").contains("eyJhbGciOi"));
+        assert_eq!(
+            request.payload().unwrap().unwrap()["WebIdentityToken"],
+            json!("eyJhbGciOi")
+        );
+    }
+
+    #[test]
+    fn deserialize_temporary_credentials() {
+        let payload = r#"{
+            "Response": {
+                "Credentials": {
+                    "Token": "tmp-token",
+                    "TmpSecretId": "AKIDtmp",
+                    "TmpSecretKey": "tmp-key"
+                },
+                "ExpiredTime": 1700000000,
+                "Expiration": "2023-11-14T22:13:20Z",
+                "RequestId": "req-sts"
+            }
+        }"#;
+        let parsed: TemporaryCredentialsResponse = serde_json::from_str(payload).unwrap();
+        let credentials = parsed.response.to_credentials();
+
+        assert_eq!(credentials.secret_id(), "AKIDtmp");
+        assert_eq!(credentials.token(), Some("tmp-token"));
+        assert_eq!(
+            credentials.expires_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        assert!(!format!("{:?}", parsed.response.credentials).contains("AKIDtmp"));
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{Error, client::endpoint::Endpoint, types::RequestId};
+use crate::{
+    Error,
+    client::{endpoint::Endpoint, paginator::Paginated},
+    types::RequestId,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -90,7 +94,7 @@ impl Endpoint for DescribeProjectsRequest {
             offset: self.offset,
         };
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeProjects request payload",
                 Box::new(source),
             )
@@ -99,6 +103,28 @@ impl Endpoint for DescribeProjectsRequest {
     }
 }
 
+impl Paginated for DescribeProjectsRequest {
+    type Item = Value;
+
+    fn page_offset(&self) -> u64 {
+        self.offset
+            .map_or(0, |offset| u64::try_from(offset).unwrap_or(0))
+    }
+
+    fn page_limit(&self) -> Option<u64> {
+        self.limit.and_then(|limit| u64::try_from(limit).ok())
+    }
+
+    fn set_page(&mut self, offset: u64, limit: u64) {
+        self.offset = Some(i32::try_from(offset).unwrap_or(i32::MAX));
+        self.limit = Some(i32::try_from(limit).unwrap_or(i32::MAX));
+    }
+
+    fn into_page(output: Self::Output) -> (Vec<Self::Item>, Option<u64>) {
+        (output.response.project_set, output.response.total_count)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,6 @@
 use crate::{
     Error,
-    client::endpoint::Endpoint,
+    client::{endpoint::Endpoint, paginator::Paginated},
     types::{DomainName, Filter, Region, RequestId, SubnetId, Tag, VpcId},
 };
 use serde::{Deserialize, Serialize};
@@ -150,7 +150,7 @@ impl Endpoint for DescribeVpcsRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeVpcs request payload",
                 Box::new(source),
             )
@@ -159,6 +159,27 @@ impl Endpoint for DescribeVpcsRequest {
     }
 }
 
+impl Paginated for DescribeVpcsRequest {
+    type Item = VpcSummary;
+
+    fn page_offset(&self) -> u64 {
+        self.offset.map_or(0, u64::from)
+    }
+
+    fn page_limit(&self) -> Option<u64> {
+        self.limit.map(u64::from)
+    }
+
+    fn set_page(&mut self, offset: u64, limit: u64) {
+        self.offset = Some(u32::try_from(offset).unwrap_or(u32::MAX));
+        self.limit = Some(u32::try_from(limit).unwrap_or(u32::MAX));
+    }
+
+    fn into_page(output: Self::Output) -> (Vec<Self::Item>, Option<u64>) {
+        (output.response.vpc_set, output.response.total_count)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateVpcResponse {
     #[serde(rename = "Response")]
@@ -273,7 +294,7 @@ impl Endpoint for CreateVpcRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize CreateVpc request payload",
                 Box::new(source),
             )
@@ -400,7 +421,7 @@ impl Endpoint for CreateSubnetRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize CreateSubnet request payload",
                 Box::new(source),
             )
@@ -530,7 +551,7 @@ impl Endpoint for DescribeSubnetsRequest {
         };
 
         let value = serde_json::to_value(payload).map_err(|source| {
-            Error::invalid_request_with_source(
+            Error::invalid_config_with_source(
                 "failed to serialize DescribeSubnets request payload",
                 Box::new(source),
             )
@@ -539,6 +560,27 @@ impl Endpoint for DescribeSubnetsRequest {
     }
 }
 
+impl Paginated for DescribeSubnetsRequest {
+    type Item = SubnetSummary;
+
+    fn page_offset(&self) -> u64 {
+        self.offset.map_or(0, u64::from)
+    }
+
+    fn page_limit(&self) -> Option<u64> {
+        self.limit.map(u64::from)
+    }
+
+    fn set_page(&mut self, offset: u64, limit: u64) {
+        self.offset = Some(u32::try_from(offset).unwrap_or(u32::MAX));
+        self.limit = Some(u32::try_from(limit).unwrap_or(u32::MAX));
+    }
+
+    fn into_page(output: Self::Output) -> (Vec<Self::Item>, Option<u64>) {
+        (output.response.subnet_set, output.response.total_count)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
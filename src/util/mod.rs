@@ -3,5 +3,7 @@ pub(crate) mod retry;
 pub(crate) mod url;
 
 pub(crate) use redact::body_snippet;
-pub(crate) use retry::{retry_after_delay, retry_delay};
-pub(crate) use url::{build_url, canonical_query_string};
+#[cfg(feature = "testing")]
+pub(crate) use redact::redact_json_value;
+pub(crate) use retry::{backoff_delay, retry_after_delay, retry_delay};
+pub(crate) use url::{build_url, canonical_query_string, encode_query_component};
@@ -16,7 +16,7 @@ fn redact_body(body: &str) -> String {
     serde_json::to_string(&value).unwrap_or_else(|_| body.to_string())
 }
 
-fn redact_json_value(value: &mut Value) {
+pub(crate) fn redact_json_value(value: &mut Value) {
     match value {
         Value::Object(map) => {
             for (key, value) in map.iter_mut() {
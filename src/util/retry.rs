@@ -2,12 +2,29 @@ use http::HeaderMap;
 use std::time::{Duration, SystemTime};
 
 pub(crate) fn retry_delay(base: Duration, attempt: usize) -> Duration {
+    backoff_delay(base, 2.0, attempt, Duration::MAX, true)
+}
+
+/// `base * multiplier^(attempt - 1)`, capped at `max`, then scaled by a 0.5..1.5 jitter factor.
+pub(crate) fn backoff_delay(
+    base: Duration,
+    multiplier: f64,
+    attempt: usize,
+    max: Duration,
+    jitter: bool,
+) -> Duration {
     if attempt == 0 {
-        Duration::ZERO
+        return Duration::ZERO;
+    }
+    let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+    let secs = base.as_secs_f64() * multiplier.powi(exponent);
+    let delay = Duration::try_from_secs_f64(secs)
+        .unwrap_or(Duration::MAX)
+        .min(max);
+    if jitter {
+        delay.mul_f64(0.5 + fastrand::f64())
     } else {
-        let pow = 2f64.powi((attempt - 1) as i32);
-        let jitter = 0.5 + fastrand::f64();
-        base.mul_f64(pow).mul_f64(jitter)
+        delay
     }
 }
 
@@ -49,6 +66,23 @@ mod tests {
         assert!(delay_2 < Duration::from_millis(300));
     }
 
+    #[test]
+    fn backoff_delay_is_capped_without_overflowing() {
+        let base = Duration::from_secs(1);
+
+        assert_eq!(
+            backoff_delay(base, 1.5, 3, Duration::from_secs(30), false),
+            Duration::from_secs_f64(2.25)
+        );
+        assert_eq!(
+            backoff_delay(base, 2.0, 10_000, Duration::from_secs(30), false),
+            Duration::from_secs(30)
+        );
+        let jittered = backoff_delay(base, 2.0, 10_000, Duration::from_secs(30), true);
+        assert!(jittered >= Duration::from_secs(15));
+        assert!(jittered < Duration::from_secs(45));
+    }
+
     #[test]
     fn retry_after_delay_parses_seconds() {
         let mut headers = HeaderMap::new();
@@ -29,7 +29,7 @@ pub(crate) fn canonical_query_string(pairs: &[(String, String)]) -> String {
     buf
 }
 
-fn encode_query_component(component: &str) -> String {
+pub(crate) fn encode_query_component(component: &str) -> String {
     percent_encode(component.as_bytes(), QUERY_ENCODE_SET).to_string()
 }
 
@@ -41,7 +41,7 @@ pub(crate) fn build_url(
 ) -> Result<Url, Error> {
     let base = format!("{scheme}://{host}");
     let mut url = Url::parse(&base)
-        .map_err(|source| Error::invalid_request_with_source("invalid request url", source))?;
+        .map_err(|source| Error::invalid_config_with_source("invalid request url", source))?;
 
     if path_segments.is_empty() {
         url.set_path("/");
@@ -51,7 +51,7 @@ pub(crate) fn build_url(
                 std::io::ErrorKind::InvalidInput,
                 "request url must be hierarchical",
             );
-            Error::invalid_request_with_source("invalid request url", source)
+            Error::invalid_config_with_source("invalid request url", source)
         })?;
         segments.clear();
         for segment in path_segments {
@@ -1,49 +1,121 @@
+/// Replayed offline; the checked-in copy follows the documented `DescribeInstances` example.
+#[cfg(feature = "testing")]
+const DESCRIBE_INSTANCES_CASSETTE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/cassettes/cvm_describe_instances.json"
+);
+
+/// Where `cargo test --features testing -- --ignored cvm_describe_instances_records_cassette`
+/// records from a real account. Review it, then copy it over the checked-in cassette.
+#[cfg(all(feature = "testing", feature = "async"))]
+const RECORDED_DESCRIBE_INSTANCES_CASSETTE: &str = concat!(
+    env!("CARGO_TARGET_TMPDIR"),
+    "/cassettes/cvm_describe_instances.json"
+);
+
 #[cfg(feature = "async")]
 mod async_contract {
-    use tencent_sdk::{Auth, Client};
+    use tencent_sdk::Client;
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     #[ignore]
     async fn billing_describe_account_balance() -> Result<(), tencent_sdk::Error> {
-        let secret_id = std::env::var("TENCENT_SECRET_ID")
-            .expect("set env TENCENT_SECRET_ID to run this contract test");
-        let secret_key = std::env::var("TENCENT_SECRET_KEY")
-            .expect("set env TENCENT_SECRET_KEY to run this contract test");
+        // Reads TENCENTCLOUD_SECRET_ID / TENCENTCLOUD_SECRET_KEY (or the legacy TENCENT_* names).
+        let client = Client::builder_tencent_cloud()?
+            .default_region("ap-guangzhou")
+            .load_env()?
+            .build()?;
 
-        let region = std::env::var("TENCENT_REGION").unwrap_or_else(|_| "ap-guangzhou".to_string());
+        let response = client.billing().describe_account_balance().await?;
+        assert!(!response.response.request_id.as_str().is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    #[ignore]
+    async fn cvm_describe_instances_records_cassette() -> Result<(), tencent_sdk::Error> {
+        use tencent_sdk::types::cvm::DescribeInstancesRequest;
 
         let client = Client::builder_tencent_cloud()?
-            .auth(Auth::tc3(secret_id, secret_key))
-            .default_region(region)
+            .default_region("ap-guangzhou")
+            .load_env()?
+            .record_cassette(super::RECORDED_DESCRIBE_INSTANCES_CASSETTE)
             .build()?;
 
-        let response = client.billing().describe_account_balance().await?;
+        let response = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await?;
         assert!(!response.response.request_id.as_str().is_empty());
         Ok(())
     }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn cvm_describe_instances_replays_cassette() -> Result<(), tencent_sdk::Error> {
+        use tencent_sdk::{Auth, types::cvm::DescribeInstancesRequest};
+
+        let client = Client::builder_tencent_cloud()?
+            .auth(Auth::tc3("replay", "replay"))
+            .default_region("ap-guangzhou")
+            .replay_cassette(super::DESCRIBE_INSTANCES_CASSETTE)?
+            .build()?;
+
+        let response = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await?;
+        let total = response.response.total_count.unwrap_or_default();
+        assert_eq!(total as usize, response.response.instance_set.len());
+        for instance in &response.response.instance_set {
+            assert!(instance.instance_id.is_some());
+            assert!(instance.cpu.is_some());
+            assert!(
+                instance
+                    .placement
+                    .as_ref()
+                    .is_some_and(|p| p.zone.is_some())
+            );
+            assert!(instance.system_disk.is_some());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "blocking")]
 mod blocking_contract {
-    use tencent_sdk::{Auth, BlockingClient};
+    use tencent_sdk::BlockingClient;
 
     #[test]
     #[ignore]
     fn billing_describe_account_balance() -> Result<(), tencent_sdk::Error> {
-        let secret_id = std::env::var("TENCENT_SECRET_ID")
-            .expect("set env TENCENT_SECRET_ID to run this contract test");
-        let secret_key = std::env::var("TENCENT_SECRET_KEY")
-            .expect("set env TENCENT_SECRET_KEY to run this contract test");
-
-        let region = std::env::var("TENCENT_REGION").unwrap_or_else(|_| "ap-guangzhou".to_string());
-
+        // Reads TENCENTCLOUD_SECRET_ID / TENCENTCLOUD_SECRET_KEY (or the legacy TENCENT_* names).
         let client = BlockingClient::builder_tencent_cloud()?
-            .auth(Auth::tc3(secret_id, secret_key))
-            .default_region(region)
+            .default_region("ap-guangzhou")
+            .load_env()?
             .build()?;
 
         let response = client.billing().describe_account_balance()?;
         assert!(!response.response.request_id.as_str().is_empty());
         Ok(())
     }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn cvm_describe_instances_replays_cassette() -> Result<(), tencent_sdk::Error> {
+        use tencent_sdk::{Auth, types::cvm::DescribeInstancesRequest};
+
+        let client = BlockingClient::builder_tencent_cloud()?
+            .auth(Auth::tc3("replay", "replay"))
+            .default_region("ap-guangzhou")
+            .replay_cassette(super::DESCRIBE_INSTANCES_CASSETTE)?
+            .build()?;
+
+        let response = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))?;
+        assert!(!response.response.instance_set.is_empty());
+        Ok(())
+    }
 }
@@ -20,6 +20,320 @@ impl Respond for TwoStepResponder {
     }
 }
 
+struct SessionProvider;
+
+impl tencent_sdk::CredentialsProvider for SessionProvider {
+    fn credentials(&self) -> Result<tencent_sdk::Credentials, tencent_sdk::Error> {
+        Ok(
+            tencent_sdk::Credentials::new("tmp_secret_id", "tmp_secret_key")
+                .with_token("session-token")
+                .with_expiry(std::time::SystemTime::now() + std::time::Duration::from_secs(3600)),
+        )
+    }
+}
+
+async fn mount_cvm_role_metadata(server: &wiremock::MockServer) {
+    use wiremock::matchers::{method, path};
+
+    wiremock::Mock::given(method("GET"))
+        .and(path("/latest/meta-data/cam/security-credentials/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("app-role"))
+        .mount(server)
+        .await;
+
+    let expired_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock after epoch")
+        .as_secs()
+        + 3600;
+    wiremock::Mock::given(method("GET"))
+        .and(path("/latest/meta-data/cam/security-credentials/app-role"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "TmpSecretId": "role_secret_id",
+            "TmpSecretKey": "role_secret_key",
+            "ExpiredTime": expired_time,
+            "Token": "role-token",
+            "Code": "Success"
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn mount_assume_role(server: &wiremock::MockServer) {
+    use wiremock::matchers::{body_json, header, method};
+
+    let expired_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock after epoch")
+        .as_secs()
+        + 3600;
+    wiremock::Mock::given(method("POST"))
+        .and(header("X-TC-Action", "AssumeRole"))
+        .and(body_json(serde_json::json!({
+            "RoleArn": "qcs::cam::uin/100:roleName/ops",
+            "RoleSessionName": "cross-account",
+            "DurationSeconds": 900
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Response": {
+                "Credentials": {
+                    "Token": "assumed-token",
+                    "TmpSecretId": "assumed_secret_id",
+                    "TmpSecretKey": "assumed_secret_key"
+                },
+                "ExpiredTime": expired_time,
+                "Expiration": "2030-01-01T00:00:00Z",
+                "RequestId": "req-assume"
+            }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+fn assume_role_provider(server: &wiremock::MockServer) -> tencent_sdk::auth::AssumeRoleProvider {
+    tencent_sdk::auth::AssumeRoleProvider::new(
+        tencent_sdk::Credentials::new("base_secret_id", "base_secret_key"),
+        "qcs::cam::uin/100:roleName/ops",
+    )
+    .session_name("cross-account")
+    .duration(std::time::Duration::from_secs(900))
+    .base_url(server.uri())
+    .endpoint_mode(tencent_sdk::client::EndpointMode::FixedHost)
+}
+
+async fn mount_web_identity(server: &wiremock::MockServer) {
+    use wiremock::matchers::{body_json, header, method};
+
+    let expired_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock after epoch")
+        .as_secs()
+        + 3600;
+    wiremock::Mock::given(method("POST"))
+        .and(header("X-TC-Action", "AssumeRoleWithWebIdentity"))
+        .and(header("Authorization", "SKIP"))
+        .and(body_json(serde_json::json!({
+            "ProviderId": "OIDC",
+            "WebIdentityToken": "projected-token",
+            "RoleArn": "qcs::cam::uin/100:roleName/pod",
+            "RoleSessionName": "pod"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Response": {
+                "Credentials": {
+                    "Token": "oidc-token",
+                    "TmpSecretId": "oidc_secret_id",
+                    "TmpSecretKey": "oidc_secret_key"
+                },
+                "ExpiredTime": expired_time,
+                "Expiration": "2030-01-01T00:00:00Z",
+                "RequestId": "req-oidc"
+            }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+fn web_identity_provider(
+    server: &wiremock::MockServer,
+    token_file: &std::path::Path,
+) -> tencent_sdk::auth::WebIdentityProvider {
+    std::fs::write(token_file, "projected-token\n").expect("write token file");
+    tencent_sdk::auth::WebIdentityProvider::new(
+        "OIDC",
+        "qcs::cam::uin/100:roleName/pod",
+        token_file,
+    )
+    .region("ap-guangzhou")
+    .session_name("pod")
+    .base_url(server.uri())
+    .endpoint_mode(tencent_sdk::client::EndpointMode::FixedHost)
+}
+
+fn signature_expire_responder(attempts: Arc<AtomicUsize>) -> TwoStepResponder {
+    let server_time = std::time::SystemTime::now() + std::time::Duration::from_secs(3600);
+    TwoStepResponder {
+        attempts,
+        first: ResponseTemplate::new(200)
+            .insert_header("Date", httpdate::fmt_http_date(server_time).as_str())
+            .set_body_json(serde_json::json!({
+                "Response": {
+                    "Error": {
+                        "Code": "AuthFailure.SignatureExpire",
+                        "Message": "The signature expired."
+                    },
+                    "RequestId": "req-expired"
+                }
+            })),
+        second: ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Response": {
+                "TotalCount": 0,
+                "InstanceSet": [],
+                "RequestId": "req-skewed"
+            }
+        })),
+    }
+}
+
+fn signed_timestamp(request: &Request) -> i64 {
+    request
+        .headers
+        .get("X-TC-Timestamp")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .expect("timestamp header")
+}
+
+struct DescribeZonesRequest;
+
+#[derive(Debug, serde::Deserialize)]
+struct DescribeZonesResponse {
+    #[serde(rename = "Response")]
+    response: DescribeZonesResult,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DescribeZonesResult {
+    #[serde(rename = "TotalCount")]
+    total_count: u64,
+    #[serde(rename = "RequestId")]
+    request_id: String,
+}
+
+impl tencent_sdk::client::Endpoint for DescribeZonesRequest {
+    type Output = DescribeZonesResponse;
+
+    fn service(&self) -> &'static str {
+        "cvm"
+    }
+
+    fn action(&self) -> &'static str {
+        "DescribeZones"
+    }
+
+    fn version(&self) -> &'static str {
+        "2017-03-12"
+    }
+}
+
+/// Answers every request in-process, failing the first `failures` with a connection reset.
+#[derive(Clone, Default)]
+struct FakeTransport {
+    requests: Arc<std::sync::Mutex<Vec<tencent_sdk::transport::TransportRequest>>>,
+    failures: Arc<AtomicUsize>,
+}
+
+impl FakeTransport {
+    fn failing(failures: usize) -> Self {
+        let transport = Self::default();
+        transport.failures.store(failures, Ordering::SeqCst);
+        transport
+    }
+
+    fn requests(&self) -> Vec<tencent_sdk::transport::TransportRequest> {
+        self.requests.lock().expect("requests lock").clone()
+    }
+
+    fn respond(
+        &self,
+        request: tencent_sdk::transport::TransportRequest,
+    ) -> Result<tencent_sdk::transport::TransportResponse, tencent_sdk::Error> {
+        self.requests
+            .lock()
+            .expect("requests lock")
+            .push(request.clone());
+        if self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                left.checked_sub(1)
+            })
+            .is_ok()
+        {
+            return Err(
+                request.transport_error(std::io::Error::from(std::io::ErrorKind::ConnectionReset))
+            );
+        }
+
+        let body = serde_json::json!({
+            "Response": {
+                "TotalCount": 0,
+                "InstanceSet": [],
+                "RequestId": "req-fake"
+            }
+        });
+        Ok(tencent_sdk::transport::TransportResponse::new(
+            http::StatusCode::OK,
+            http::HeaderMap::new(),
+            body.to_string(),
+        ))
+    }
+}
+
+#[cfg(feature = "async")]
+impl tencent_sdk::transport::AsyncTransport for FakeTransport {
+    fn send(
+        &self,
+        request: tencent_sdk::transport::TransportRequest,
+    ) -> tencent_sdk::transport::TransportFuture<'_> {
+        Box::pin(std::future::ready(self.respond(request)))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl tencent_sdk::transport::BlockingTransport for FakeTransport {
+    fn send(
+        &self,
+        request: tencent_sdk::transport::TransportRequest,
+    ) -> Result<tencent_sdk::transport::TransportResponse, tencent_sdk::Error> {
+        self.respond(request)
+    }
+}
+
+#[cfg(feature = "async")]
+struct LegacyDescribeZonesRequest;
+
+#[cfg(feature = "async")]
+impl tencent_sdk::client::Endpoint for LegacyDescribeZonesRequest {
+    type Output = DescribeZonesResponse;
+
+    fn method(&self) -> http::Method {
+        http::Method::GET
+    }
+
+    fn signature_method(&self) -> Option<tencent_sdk::client::SignatureMethod> {
+        Some(tencent_sdk::client::SignatureMethod::HmacSha256)
+    }
+
+    fn service(&self) -> &'static str {
+        "cvm"
+    }
+
+    fn action(&self) -> &'static str {
+        "DescribeZones"
+    }
+
+    fn version(&self) -> &'static str {
+        "2017-03-12"
+    }
+
+    fn payload(&self) -> Result<Option<serde_json::Value>, tencent_sdk::Error> {
+        Ok(Some(serde_json::json!({ "Zones": ["ap-guangzhou-3"] })))
+    }
+}
+
+#[cfg(feature = "async")]
+fn describe_zones_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "Response": {
+            "TotalCount": 1,
+            "RequestId": "req-zones"
+        }
+    }))
+}
+
 #[cfg(feature = "async")]
 mod async_client {
     use httpdate::fmt_http_date;
@@ -27,11 +341,14 @@ mod async_client {
     use std::sync::Arc;
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::{Duration, SystemTime};
+    use tencent_sdk::auth::{CvmRoleProvider, ProfileProvider};
     use tencent_sdk::client::{EndpointMode, RequestOptions};
     use tencent_sdk::error::ErrorKind;
     use tencent_sdk::types::cvm::{DescribeInstancesRequest, RunInstancesRequest};
     use tencent_sdk::{Auth, Client};
-    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::matchers::{
+        body_json, header, method, path, query_param, query_param_is_missing,
+    };
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn build_client(server: &MockServer) -> Client {
@@ -365,89 +682,76 @@ mod async_client {
         assert_eq!(attempts.load(Ordering::SeqCst), 2);
         assert_eq!(resp.response.request_id.as_str(), "req-run-ok");
     }
-}
-
-#[cfg(feature = "blocking")]
-mod blocking_client {
-    use httpdate::fmt_http_date;
-    use serde_json::json;
-    use std::sync::Arc;
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::time::{Duration, SystemTime};
-    use tencent_sdk::client::{EndpointMode, RequestOptions};
-    use tencent_sdk::error::ErrorKind;
-    use tencent_sdk::types::cvm::{DescribeInstancesRequest, RunInstancesRequest};
-    use tencent_sdk::{Auth, BlockingClient};
-    use wiremock::matchers::{body_json, header, method, path};
-    use wiremock::{Mock, MockServer, ResponseTemplate};
-
-    fn build_client(server: &MockServer) -> BlockingClient {
-        BlockingClient::builder(server.uri())
-            .expect("build client builder")
-            .endpoint_mode(EndpointMode::FixedHost)
-            .auth(Auth::tc3("secret_id", "secret_key"))
-            .default_region("ap-guangzhou")
-            .no_system_proxy(true)
-            .build()
-            .expect("build client")
-    }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_parses_success_response() {
+    async fn async_client_executes_user_defined_endpoint() {
         let server = MockServer::start().await;
 
         Mock::given(method("POST"))
             .and(path("/"))
-            .and(header("X-TC-Action", "DescribeInstances"))
-            .and(body_json(json!({"Limit": 1})))
+            .and(header("X-TC-Action", "DescribeZones"))
+            .and(header("X-TC-Version", "2017-03-12"))
+            .and(header("X-TC-Region", "ap-guangzhou"))
+            .and(body_json(json!({})))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "Response": {
-                    "TotalCount": 0,
-                    "InstanceSet": [],
-                    "RequestId": "req-1"
+                    "TotalCount": 3,
+                    "RequestId": "req-zones"
                 }
             })))
             .mount(&server)
             .await;
 
         let client = build_client(&server);
-        let request = DescribeInstancesRequest::new().limit(1);
-
-        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+        let resp = client
+            .execute(&super::DescribeZonesRequest, None)
             .await
-            .expect("join blocking task")
             .expect("request succeeds");
 
-        assert_eq!(resp.response.request_id.as_str(), "req-1");
-        assert_eq!(resp.response.total_count, Some(0));
-        assert!(resp.response.instance_set.is_empty());
+        assert_eq!(resp.response.total_count, 3);
+        assert_eq!(resp.response.request_id, "req-zones");
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_surfaces_http_status_error() {
+    async fn async_client_call_raw_returns_untyped_response() {
         let server = MockServer::start().await;
 
         Mock::given(method("POST"))
             .and(path("/"))
-            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .and(header("X-TC-Action", "DescribeZones"))
+            .and(header("X-TC-Version", "2017-03-12"))
+            .and(header("X-TC-Region", "ap-shanghai"))
+            .and(body_json(json!({"Product": "cvm"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "ZoneSet": [{"Zone": "ap-shanghai-2"}],
+                    "RequestId": "req-raw"
+                }
+            })))
             .mount(&server)
             .await;
 
         let client = build_client(&server);
-        let request = DescribeInstancesRequest::new().limit(1);
-
-        let err = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+        let resp = client
+            .call_raw(
+                "cvm",
+                "2017-03-12",
+                "DescribeZones",
+                Some("ap-shanghai"),
+                json!({"Product": "cvm"}),
+            )
             .await
-            .expect("join blocking task")
-            .expect_err("status error surfaced");
+            .expect("request succeeds");
 
-        assert_eq!(err.kind(), ErrorKind::Api);
-        assert_eq!(err.status().map(|status| status.as_u16()), Some(500));
-        assert_eq!(err.body_snippet(), Some("boom"));
+        assert_eq!(
+            resp.request_id.as_ref().map(|id| id.as_str()),
+            Some("req-raw")
+        );
+        assert_eq!(resp.response["ZoneSet"][0]["Zone"], json!("ap-shanghai-2"));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_surfaces_service_error() {
+    async fn async_client_call_raw_classifies_service_errors() {
         let server = MockServer::start().await;
 
         Mock::given(method("POST"))
@@ -455,164 +759,611 @@ mod blocking_client {
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "Response": {
                     "Error": {
-                        "Code": "AuthFailure.SignatureFailure",
-                        "Message": "signature mismatch"
+                        "Code": "ResourceNotFound.InstanceNotExist",
+                        "Message": "missing"
                     },
-                    "RequestId": "req-123"
+                    "RequestId": "req-raw-err"
                 }
             })))
             .mount(&server)
             .await;
 
         let client = build_client(&server);
-        let request = DescribeInstancesRequest::new().limit(1);
-
-        let err = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+        let err = client
+            .call_raw("cvm", "2017-03-12", "DescribeInstances", None, json!({}))
             .await
-            .expect("join blocking task")
             .expect_err("service error surfaced");
 
-        assert_eq!(err.kind(), ErrorKind::Auth);
-        assert_eq!(err.code(), Some("AuthFailure.SignatureFailure"));
-        assert_eq!(err.message(), Some("signature mismatch"));
-        assert_eq!(err.request_id(), Some("req-123"));
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(err.request_id(), Some("req-raw-err"));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_retries_retryable_service_errors() {
+    async fn async_client_signs_with_provider_credentials() {
         let server = MockServer::start().await;
-        let attempts = Arc::new(AtomicUsize::new(0));
 
         Mock::given(method("POST"))
             .and(path("/"))
-            .respond_with(super::TwoStepResponder {
-                attempts: attempts.clone(),
-                first: ResponseTemplate::new(200).set_body_json(json!({
-                    "Response": {
-                        "Error": {
-                            "Code": "RequestLimitExceeded",
-                            "Message": "throttled"
-                        },
-                        "RequestId": "req-retry"
-                    }
-                })),
-                second: ResponseTemplate::new(200).set_body_json(json!({
-                    "Response": {
-                        "TotalCount": 0,
-                        "InstanceSet": [],
-                        "RequestId": "req-ok"
-                    }
-                })),
-            })
+            .and(header("X-TC-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-provider"
+                }
+            })))
+            .expect(2)
             .mount(&server)
             .await;
 
-        let client = BlockingClient::builder(server.uri())
+        let client = Client::builder(server.uri())
             .expect("build client builder")
             .endpoint_mode(EndpointMode::FixedHost)
-            .auth(Auth::tc3("secret_id", "secret_key"))
+            .auth(Auth::provider(super::SessionProvider))
             .default_region("ap-guangzhou")
             .no_system_proxy(true)
-            .retry(1, Duration::from_millis(0))
             .build()
             .expect("build client");
 
         let request = DescribeInstancesRequest::new().limit(1);
+        for _ in 0..2 {
+            let resp = client
+                .cvm()
+                .describe_instances(&request)
+                .await
+                .expect("request succeeds");
+            assert_eq!(resp.response.request_id.as_str(), "req-provider");
+        }
 
-        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
-            .await
-            .expect("join blocking task")
-            .expect("eventual success");
-
-        assert_eq!(attempts.load(Ordering::SeqCst), 2);
-        assert_eq!(resp.response.request_id.as_str(), "req-ok");
+        let requests = server.received_requests().await.expect("recorded requests");
+        let authorization = requests[0]
+            .headers
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .expect("authorization header");
+        assert!(authorization.contains("Credential=tmp_secret_id/"));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_respects_retry_after_on_http_429() {
+    async fn async_client_loads_tccli_profile() {
         let server = MockServer::start().await;
-        let attempts = Arc::new(AtomicUsize::new(0));
 
         Mock::given(method("POST"))
             .and(path("/"))
-            .respond_with(super::TwoStepResponder {
-                attempts: attempts.clone(),
-                first: ResponseTemplate::new(429)
-                    .insert_header("Retry-After", "0")
-                    .set_body_string("rate limited"),
-                second: ResponseTemplate::new(200).set_body_json(json!({
-                    "Response": {
-                        "TotalCount": 0,
-                        "InstanceSet": [],
-                        "RequestId": "req-429-ok"
-                    }
-                })),
-            })
+            .and(header("X-TC-Region", "ap-shanghai"))
+            .and(header("X-TC-Token", "profile-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-profile"
+                }
+            })))
+            .expect(1)
             .mount(&server)
             .await;
 
-        let client = BlockingClient::builder(server.uri())
+        let dir =
+            std::env::temp_dir().join(format!("tencent-sdk-http-profile-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create profile dir");
+        std::fs::write(
+            dir.join("test.credential"),
+            json!({
+                "secretId": "profile_secret_id",
+                "secretKey": "profile_secret_key",
+                "token": "profile-token"
+            })
+            .to_string(),
+        )
+        .expect("write credential file");
+        std::fs::write(
+            dir.join("test.configure"),
+            json!({
+                "_sys_param": { "region": "ap-shanghai" },
+                "cvm": { "endpoint": "cvm.tencentcloudapi.com" }
+            })
+            .to_string(),
+        )
+        .expect("write configure file");
+
+        let client = Client::builder(server.uri())
             .expect("build client builder")
             .endpoint_mode(EndpointMode::FixedHost)
-            .auth(Auth::tc3("secret_id", "secret_key"))
-            .default_region("ap-guangzhou")
+            .load_profile(ProfileProvider::new("test").with_config_dir(&dir))
+            .expect("load profile")
             .no_system_proxy(true)
-            .retry(1, Duration::from_millis(0))
             .build()
             .expect("build client");
 
-        let request = DescribeInstancesRequest::new().limit(1);
-        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+        let resp = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
             .await
-            .expect("join blocking task")
-            .expect("eventual success");
-
-        assert_eq!(attempts.load(Ordering::SeqCst), 2);
-        assert_eq!(resp.response.request_id.as_str(), "req-429-ok");
+            .expect("request succeeds");
+        assert_eq!(resp.response.request_id.as_str(), "req-profile");
+        std::fs::remove_dir_all(&dir).expect("remove profile dir");
     }
-
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_respects_retry_after_http_date_on_http_429() {
+    async fn async_client_uses_cvm_role_credentials() {
         let server = MockServer::start().await;
-        let attempts = Arc::new(AtomicUsize::new(0));
-        let retry_at = fmt_http_date(SystemTime::now());
+        super::mount_cvm_role_metadata(&server).await;
 
         Mock::given(method("POST"))
             .and(path("/"))
-            .respond_with(super::TwoStepResponder {
-                attempts: attempts.clone(),
-                first: ResponseTemplate::new(429)
-                    .insert_header("Retry-After", retry_at)
-                    .set_body_string("rate limited"),
-                second: ResponseTemplate::new(200).set_body_json(json!({
-                    "Response": {
-                        "TotalCount": 0,
-                        "InstanceSet": [],
-                        "RequestId": "req-429-date-ok"
-                    }
-                })),
-            })
+            .and(header("X-TC-Token", "role-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-role"
+                }
+            })))
+            .expect(2)
             .mount(&server)
             .await;
 
-        let client = BlockingClient::builder(server.uri())
+        let client = Client::builder(server.uri())
             .expect("build client builder")
             .endpoint_mode(EndpointMode::FixedHost)
-            .auth(Auth::tc3("secret_id", "secret_key"))
+            .auth(Auth::provider(
+                CvmRoleProvider::new().metadata_base_url(server.uri()),
+            ))
             .default_region("ap-guangzhou")
             .no_system_proxy(true)
-            .retry(1, Duration::from_millis(0))
             .build()
             .expect("build client");
 
         let request = DescribeInstancesRequest::new().limit(1);
-        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
-            .await
-            .expect("join blocking task")
-            .expect("eventual success");
-
-        assert_eq!(attempts.load(Ordering::SeqCst), 2);
-        assert_eq!(resp.response.request_id.as_str(), "req-429-date-ok");
+        for _ in 0..2 {
+            let resp = client
+                .cvm()
+                .describe_instances(&request)
+                .await
+                .expect("request succeeds");
+            assert_eq!(resp.response.request_id.as_str(), "req-role");
+        }
+    }
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_client_assumes_role_once() {
+        let server = MockServer::start().await;
+        super::mount_assume_role(&server).await;
+
+        Mock::given(method("POST"))
+            .and(header("X-TC-Action", "DescribeInstances"))
+            .and(header("X-TC-Token", "assumed-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-assumed"
+                }
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::provider(super::assume_role_provider(&server)))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        for _ in 0..2 {
+            client
+                .cvm()
+                .describe_instances(&request)
+                .await
+                .expect("request succeeds");
+        }
+
+        let requests = server.received_requests().await.expect("recorded requests");
+        let assume_role = requests
+            .iter()
+            .find(|request| {
+                request
+                    .headers
+                    .get("X-TC-Action")
+                    .is_some_and(|v| v == "AssumeRole")
+            })
+            .expect("AssumeRole request");
+        let authorization = assume_role
+            .headers
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .expect("authorization header");
+        assert!(authorization.contains("Credential=base_secret_id/"));
+    }
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_client_uses_web_identity_credentials() {
+        let server = MockServer::start().await;
+        super::mount_web_identity(&server).await;
+
+        Mock::given(method("POST"))
+            .and(header("X-TC-Action", "DescribeInstances"))
+            .and(header("X-TC-Token", "oidc-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-oidc-call"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let token_file = std::env::temp_dir().join(format!(
+            "tencent-sdk-http-async-token-{}",
+            std::process::id()
+        ));
+        let client = Client::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::provider(super::web_identity_provider(
+                &server,
+                &token_file,
+            )))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await
+            .expect("request succeeds");
+        std::fs::remove_file(&token_file).expect("remove token file");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_client_corrects_clock_skew_and_retries_once() {
+        let server = MockServer::start().await;
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(super::signature_expire_responder(attempts.clone()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .retry(0, Duration::ZERO)
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+        let resp = client
+            .execute_with_metadata(&DescribeInstancesRequest::new().limit(1), None)
+            .await
+            .expect("request succeeds after clock correction without a retry budget");
+
+        assert_eq!(resp.output().response.request_id.as_str(), "req-skewed");
+        assert_eq!(resp.metadata().attempts(), 2);
+        assert!((3590..=3610).contains(&client.clock_offset_seconds()));
+
+        let requests = server.received_requests().await.expect("recorded requests");
+        let skew = super::signed_timestamp(&requests[1]) - super::signed_timestamp(&requests[0]);
+        assert!((3590..=3610).contains(&skew));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_client_signs_legacy_endpoint_in_query_string() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(query_param("Action", "DescribeZones"))
+            .and(query_param("Version", "2017-03-12"))
+            .and(query_param("Region", "ap-guangzhou"))
+            .and(query_param("SecretId", "secret_id"))
+            .and(query_param("SignatureMethod", "HmacSHA256"))
+            .and(query_param("Zones.0", "ap-guangzhou-3"))
+            .and(query_param_is_missing("Token"))
+            .respond_with(super::describe_zones_response())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let resp = client
+            .execute(&super::LegacyDescribeZonesRequest, None)
+            .await
+            .expect("request succeeds");
+        assert_eq!(resp.response.request_id, "req-zones");
+
+        let requests = server.received_requests().await.expect("recorded requests");
+        let query: std::collections::HashMap<_, _> =
+            requests[0].url.query_pairs().into_owned().collect();
+        assert!(query.contains_key("Nonce"));
+        assert!(query.contains_key("Timestamp"));
+        assert!(query.contains_key("Signature"));
+        assert!(requests[0].headers.get("Authorization").is_none());
+        assert!(requests[0].body.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_client_sends_through_custom_transport() {
+        let transport = super::FakeTransport::failing(1);
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .retry(1, Duration::ZERO)
+            .transport(transport.clone())
+            .build()
+            .expect("build client");
+
+        let resp = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await
+            .expect("request succeeds after retry");
+        assert_eq!(resp.response.request_id.as_str(), "req-fake");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].url(), "https://cvm.tencentcloudapi.com/");
+        assert_eq!(requests[1].headers()["X-TC-Action"], "DescribeInstances");
+        assert_eq!(requests[1].body(), Some(r#"{"Limit":1}"#));
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_client {
+    use httpdate::fmt_http_date;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{Duration, SystemTime};
+    use tencent_sdk::auth::CvmRoleProvider;
+    use tencent_sdk::client::{EndpointMode, RequestOptions, SignatureMethod};
+    use tencent_sdk::error::ErrorKind;
+    use tencent_sdk::types::cvm::{DescribeInstancesRequest, RunInstancesRequest};
+    use tencent_sdk::{Auth, BlockingClient};
+    use wiremock::matchers::{body_json, body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn build_client(server: &MockServer) -> BlockingClient {
+        BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client")
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_parses_success_response() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("X-TC-Action", "DescribeInstances"))
+            .and(body_json(json!({"Limit": 1})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-1"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let request = DescribeInstancesRequest::new().limit(1);
+
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("request succeeds");
+
+        assert_eq!(resp.response.request_id.as_str(), "req-1");
+        assert_eq!(resp.response.total_count, Some(0));
+        assert!(resp.response.instance_set.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_surfaces_http_status_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let request = DescribeInstancesRequest::new().limit(1);
+
+        let err = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect_err("status error surfaced");
+
+        assert_eq!(err.kind(), ErrorKind::Api);
+        assert_eq!(err.status().map(|status| status.as_u16()), Some(500));
+        assert_eq!(err.body_snippet(), Some("boom"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_surfaces_service_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "Error": {
+                        "Code": "AuthFailure.SignatureFailure",
+                        "Message": "signature mismatch"
+                    },
+                    "RequestId": "req-123"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let request = DescribeInstancesRequest::new().limit(1);
+
+        let err = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect_err("service error surfaced");
+
+        assert_eq!(err.kind(), ErrorKind::Auth);
+        assert_eq!(err.code(), Some("AuthFailure.SignatureFailure"));
+        assert_eq!(err.message(), Some("signature mismatch"));
+        assert_eq!(err.request_id(), Some("req-123"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_retries_retryable_service_errors() {
+        let server = MockServer::start().await;
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(super::TwoStepResponder {
+                attempts: attempts.clone(),
+                first: ResponseTemplate::new(200).set_body_json(json!({
+                    "Response": {
+                        "Error": {
+                            "Code": "RequestLimitExceeded",
+                            "Message": "throttled"
+                        },
+                        "RequestId": "req-retry"
+                    }
+                })),
+                second: ResponseTemplate::new(200).set_body_json(json!({
+                    "Response": {
+                        "TotalCount": 0,
+                        "InstanceSet": [],
+                        "RequestId": "req-ok"
+                    }
+                })),
+            })
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .retry(1, Duration::from_millis(0))
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("eventual success");
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(resp.response.request_id.as_str(), "req-ok");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_respects_retry_after_on_http_429() {
+        let server = MockServer::start().await;
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(super::TwoStepResponder {
+                attempts: attempts.clone(),
+                first: ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "0")
+                    .set_body_string("rate limited"),
+                second: ResponseTemplate::new(200).set_body_json(json!({
+                    "Response": {
+                        "TotalCount": 0,
+                        "InstanceSet": [],
+                        "RequestId": "req-429-ok"
+                    }
+                })),
+            })
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .retry(1, Duration::from_millis(0))
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("eventual success");
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(resp.response.request_id.as_str(), "req-429-ok");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_respects_retry_after_http_date_on_http_429() {
+        let server = MockServer::start().await;
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let retry_at = fmt_http_date(SystemTime::now());
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(super::TwoStepResponder {
+                attempts: attempts.clone(),
+                first: ResponseTemplate::new(429)
+                    .insert_header("Retry-After", retry_at)
+                    .set_body_string("rate limited"),
+                second: ResponseTemplate::new(200).set_body_json(json!({
+                    "Response": {
+                        "TotalCount": 0,
+                        "InstanceSet": [],
+                        "RequestId": "req-429-date-ok"
+                    }
+                })),
+            })
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .retry(1, Duration::from_millis(0))
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("eventual success");
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(resp.response.request_id.as_str(), "req-429-date-ok");
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -705,4 +1456,327 @@ mod blocking_client {
         assert_eq!(attempts.load(Ordering::SeqCst), 2);
         assert_eq!(resp.response.request_id.as_str(), "req-run-ok");
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_executes_user_defined_endpoint() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("X-TC-Action", "DescribeZones"))
+            .and(header("X-TC-Version", "2017-03-12"))
+            .and(body_json(json!({})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 3,
+                    "RequestId": "req-zones"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let resp =
+            tokio::task::spawn_blocking(move || client.execute(&super::DescribeZonesRequest, None))
+                .await
+                .expect("join blocking task")
+                .expect("request succeeds");
+
+        assert_eq!(resp.response.total_count, 3);
+        assert_eq!(resp.response.request_id, "req-zones");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_call_raw_returns_untyped_response() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("X-TC-Action", "DescribeZones"))
+            .and(body_json(json!({"Product": "cvm"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "ZoneSet": [{"Zone": "ap-guangzhou-3"}],
+                    "RequestId": "req-raw"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let resp = tokio::task::spawn_blocking(move || {
+            client.call_raw(
+                "cvm",
+                "2017-03-12",
+                "DescribeZones",
+                None,
+                json!({"Product": "cvm"}),
+            )
+        })
+        .await
+        .expect("join blocking task")
+        .expect("request succeeds");
+
+        assert_eq!(
+            resp.request_id.as_ref().map(|id| id.as_str()),
+            Some("req-raw")
+        );
+        assert_eq!(resp.response["ZoneSet"][0]["Zone"], json!("ap-guangzhou-3"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_signs_with_provider_credentials() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("X-TC-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-provider"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::provider(super::SessionProvider))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("request succeeds");
+
+        assert_eq!(resp.response.request_id.as_str(), "req-provider");
+    }
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_uses_cvm_role_credentials() {
+        let server = MockServer::start().await;
+        super::mount_cvm_role_metadata(&server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("X-TC-Token", "role-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-role"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::provider(
+                CvmRoleProvider::new()
+                    .role("app-role")
+                    .metadata_base_url(server.uri()),
+            ))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("request succeeds");
+
+        assert_eq!(resp.response.request_id.as_str(), "req-role");
+    }
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_assumes_role() {
+        let server = MockServer::start().await;
+        super::mount_assume_role(&server).await;
+
+        Mock::given(method("POST"))
+            .and(header("X-TC-Action", "DescribeInstances"))
+            .and(header("X-TC-Token", "assumed-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-assumed"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::provider(super::assume_role_provider(&server)))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        let resp = tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("request succeeds");
+
+        assert_eq!(resp.response.request_id.as_str(), "req-assumed");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_uses_web_identity_credentials() {
+        let server = MockServer::start().await;
+        super::mount_web_identity(&server).await;
+
+        Mock::given(method("POST"))
+            .and(header("X-TC-Action", "DescribeInstances"))
+            .and(header("X-TC-Token", "oidc-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-oidc-call"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let token_file = std::env::temp_dir().join(format!(
+            "tencent-sdk-http-blocking-token-{}",
+            std::process::id()
+        ));
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::provider(super::web_identity_provider(
+                &server,
+                &token_file,
+            )))
+            .default_region("ap-guangzhou")
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        let request = DescribeInstancesRequest::new().limit(1);
+        tokio::task::spawn_blocking(move || client.cvm().describe_instances(&request))
+            .await
+            .expect("join blocking task")
+            .expect("request succeeds");
+        std::fs::remove_file(&token_file).expect("remove token file");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_corrects_clock_skew_and_retries_once() {
+        let server = MockServer::start().await;
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(super::signature_expire_responder(attempts.clone()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = build_client(&server);
+        let worker = client.clone();
+        let resp = tokio::task::spawn_blocking(move || {
+            worker
+                .cvm()
+                .describe_instances(&DescribeInstancesRequest::new().limit(1))
+        })
+        .await
+        .expect("join blocking task")
+        .expect("request succeeds after clock correction");
+
+        assert_eq!(resp.response.request_id.as_str(), "req-skewed");
+        assert!((3590..=3610).contains(&client.clock_offset_seconds()));
+
+        let requests = server.received_requests().await.expect("recorded requests");
+        let skew = super::signed_timestamp(&requests[1]) - super::signed_timestamp(&requests[0]);
+        assert!((3590..=3610).contains(&skew));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_signs_legacy_requests_as_form() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("Content-Type", "application/x-www-form-urlencoded"))
+            .and(body_string_contains("Action=DescribeInstances"))
+            .and(body_string_contains("Limit=1"))
+            .and(body_string_contains("SignatureMethod=HmacSHA1"))
+            .and(body_string_contains("&Signature="))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Response": {
+                    "TotalCount": 0,
+                    "InstanceSet": [],
+                    "RequestId": "req-legacy"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = BlockingClient::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::FixedHost)
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .signature_method(SignatureMethod::HmacSha1)
+            .no_system_proxy(true)
+            .build()
+            .expect("build client");
+
+        let resp = tokio::task::spawn_blocking(move || {
+            client
+                .cvm()
+                .describe_instances(&DescribeInstancesRequest::new().limit(1))
+        })
+        .await
+        .expect("join blocking task")
+        .expect("request succeeds");
+        assert_eq!(resp.response.request_id.as_str(), "req-legacy");
+
+        let requests = server.received_requests().await.expect("recorded requests");
+        assert!(requests[0].headers.get("X-TC-Action").is_none());
+    }
+
+    #[test]
+    fn blocking_client_sends_through_custom_transport() {
+        let transport = super::FakeTransport::failing(1);
+        let client = BlockingClient::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .retry(1, Duration::ZERO)
+            .transport(transport.clone())
+            .build()
+            .expect("build client");
+
+        let resp = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .expect("request succeeds after retry");
+        assert_eq!(resp.response.request_id.as_str(), "req-fake");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].url(), "https://cvm.tencentcloudapi.com/");
+        assert_eq!(requests[1].headers()["X-TC-Action"], "DescribeInstances");
+    }
 }
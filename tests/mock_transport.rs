@@ -0,0 +1,1111 @@
+#![cfg(feature = "testing")]
+
+#[cfg(feature = "async")]
+mod async_client {
+    use http::StatusCode;
+    use serde_json::json;
+    use std::time::Duration;
+    use tencent_sdk::auth::ProfileProvider;
+    use tencent_sdk::client::{
+        CircuitBreaker, CircuitState, EndpointMode, Interceptor, InterceptorContext, NoRetryPolicy,
+        RateLimit, RateLimiter, RequestOptions, StandardRetryPolicy, WaitOptions,
+    };
+    use tencent_sdk::error::ErrorKind;
+    use tencent_sdk::testing::MockTransport;
+    use tencent_sdk::types::cvm::DescribeInstancesRequest;
+    use tencent_sdk::types::dns::{CreateTxtRecordRequest, DeleteRecordRequest};
+    use tencent_sdk::{Auth, Client, Error};
+
+    fn build_client(mock: &MockTransport) -> Client {
+        Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .retry(2, Duration::ZERO)
+            .transport(mock.clone())
+            .build()
+            .expect("build client")
+    }
+
+    #[tokio::test]
+    async fn async_client_replays_scripted_replies_in_order() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .fail(std::io::ErrorKind::ConnectionReset)
+            .respond_status(StatusCode::SERVICE_UNAVAILABLE, "busy")
+            .respond(json!({ "TotalCount": 1, "InstanceSet": [] }));
+        let client = build_client(&mock);
+
+        let resp = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await
+            .expect("request succeeds on the third attempt");
+
+        assert_eq!(resp.response.total_count, Some(1));
+        assert_eq!(resp.response.request_id.as_str(), "mock-request-id");
+
+        let requests = mock.requests_for("cvm", "DescribeInstances");
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].payload(), Some(json!({ "Limit": 1 })));
+        assert_eq!(
+            requests[2].request().headers()["X-TC-Region"],
+            "ap-guangzhou"
+        );
+        assert!(
+            requests[2].request().headers()["Authorization"]
+                .to_str()
+                .unwrap()
+                .starts_with("TC3-HMAC-SHA256 Credential=secret_id/")
+        );
+    }
+
+    #[tokio::test]
+    async fn async_client_surfaces_scripted_service_errors() {
+        let mock = MockTransport::new();
+        mock.on("dnspod", "CreateRecord")
+            .respond(json!({ "RecordId": 42 }));
+        mock.on("dnspod", "DeleteRecord")
+            .respond_error("InvalidParameter.RecordIdInvalid", "record id is invalid");
+        let client = build_client(&mock);
+
+        let created = client
+            .dns()
+            .create_txt_record(&CreateTxtRecordRequest::new(
+                "example.com",
+                "_acme-challenge",
+                "默认",
+                "token",
+            ))
+            .await
+            .expect("create succeeds");
+        assert_eq!(created.response.record_id, Some(42));
+
+        let err = client
+            .dns()
+            .delete_record(&DeleteRecordRequest::new("example.com", 7))
+            .await
+            .expect_err("delete fails");
+        assert_eq!(err.code(), Some("InvalidParameter.RecordIdInvalid"));
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn async_client_paginates_until_total_count() {
+        use futures_util::StreamExt;
+
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .respond(json!({
+                "TotalCount": 3,
+                "InstanceSet": [{ "InstanceId": "ins-1" }, { "InstanceId": "ins-2" }]
+            }))
+            .respond(json!({
+                "TotalCount": 3,
+                "InstanceSet": [{ "InstanceId": "ins-3" }]
+            }));
+        let client = build_client(&mock);
+
+        let ids: Vec<String> = client
+            .paginate(DescribeInstancesRequest::new())
+            .page_size(2)
+            .into_stream()
+            .map(|instance| {
+                instance
+                    .expect("page succeeds")
+                    .instance_id
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+            .await;
+
+        assert_eq!(ids, ["ins-1", "ins-2", "ins-3"]);
+        let requests = mock.requests_for("cvm", "DescribeInstances");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].payload(),
+            Some(json!({ "Limit": 2, "Offset": 0 }))
+        );
+        assert_eq!(
+            requests[1].payload(),
+            Some(json!({ "Limit": 2, "Offset": 2 }))
+        );
+    }
+
+    #[tokio::test]
+    async fn async_client_paginator_respects_max_items() {
+        use futures_util::StreamExt;
+
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances").respond(json!({
+            "TotalCount": 10,
+            "InstanceSet": [{ "InstanceId": "ins-1" }, { "InstanceId": "ins-2" }]
+        }));
+        let client = build_client(&mock);
+
+        let instances: Vec<_> = client
+            .paginate(DescribeInstancesRequest::new().limit(2))
+            .max_items(3)
+            .into_stream()
+            .collect()
+            .await;
+
+        assert_eq!(instances.len(), 3);
+        let requests = mock.requests_for("cvm", "DescribeInstances");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].payload(),
+            Some(json!({ "Limit": 1, "Offset": 2 }))
+        );
+    }
+
+    #[tokio::test]
+    async fn async_client_waits_until_instances_are_running() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }))
+            .respond(json!({
+                "TotalCount": 1,
+                "InstanceSet": [{
+                    "InstanceId": "ins-1",
+                    "InstanceState": "RUNNING",
+                    "LatestOperationState": "OPERATING"
+                }]
+            }))
+            .respond(json!({
+                "TotalCount": 1,
+                "InstanceSet": [{
+                    "InstanceId": "ins-1",
+                    "InstanceState": "RUNNING",
+                    "LatestOperationState": "SUCCESS"
+                }]
+            }));
+        let client = build_client(&mock);
+
+        let instances = client
+            .cvm()
+            .wait_until_running(
+                "ap-guangzhou",
+                ["ins-1"],
+                &WaitOptions::new().poll_interval(Duration::from_millis(1)),
+            )
+            .await
+            .expect("instance reaches RUNNING");
+
+        assert_eq!(instances.len(), 1);
+        let requests = mock.requests_for("cvm", "DescribeInstances");
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[0].payload(),
+            Some(json!({ "InstanceIds": ["ins-1"], "Limit": 1 }))
+        );
+    }
+
+    #[tokio::test]
+    async fn async_client_waiter_stops_on_failed_launch() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances").respond(json!({
+            "TotalCount": 2,
+            "InstanceSet": [
+                { "InstanceId": "ins-1", "InstanceState": "PENDING" },
+                { "InstanceId": "ins-2", "InstanceState": "LAUNCH_FAILED" }
+            ]
+        }));
+        let client = build_client(&mock);
+
+        let err = client
+            .cvm()
+            .wait_until_running("ap-guangzhou", ["ins-1", "ins-2"], &WaitOptions::new())
+            .await
+            .expect_err("launch failed");
+
+        assert_eq!(err.kind(), ErrorKind::WaiterFailed);
+        assert_eq!(mock.requests_for("cvm", "DescribeInstances").len(), 1);
+
+        let err = client
+            .cvm()
+            .wait_until_stopped("ap-guangzhou", Vec::<String>::new(), &WaitOptions::new())
+            .await
+            .expect_err("no instance ids");
+        assert_eq!(err.kind(), ErrorKind::InvalidConfig);
+    }
+
+    #[tokio::test]
+    async fn async_client_waiter_times_out_with_last_states() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances").respond(json!({
+            "TotalCount": 1,
+            "InstanceSet": [{ "InstanceId": "ins-1", "InstanceState": "STOPPING" }]
+        }));
+        let client = build_client(&mock);
+
+        let err = client
+            .cvm()
+            .wait_until_stopped(
+                "ap-guangzhou",
+                ["ins-1", "ins-2"],
+                &WaitOptions::new()
+                    .poll_interval(Duration::from_millis(5))
+                    .timeout(Duration::from_millis(20)),
+            )
+            .await
+            .expect_err("instances never stop");
+
+        assert_eq!(err.kind(), ErrorKind::WaiterTimeout);
+        let Error::WaiterTimeout(timeout) = &err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(timeout.waiter(), "InstanceStopped");
+        assert!(timeout.attempts() >= 2);
+        let states = timeout.last_states();
+        assert_eq!(states[0].resource_id(), "ins-1");
+        assert_eq!(states[0].state(), Some("STOPPING"));
+        assert_eq!(states[1].state(), None);
+        assert!(err.to_string().contains("ins-1=STOPPING, ins-2=<missing>"));
+    }
+
+    #[tokio::test]
+    async fn async_client_waits_until_certificate_is_issued() {
+        let mock = MockTransport::new();
+        mock.on("ssl", "DescribeCertificate")
+            .respond(json!({ "CertificateId": "cert-1", "Status": 0 }))
+            .respond(json!({ "CertificateId": "cert-1", "Status": 1 }));
+        let client = build_client(&mock);
+
+        client
+            .ssl()
+            .wait_until_certificate_issued(
+                "cert-1",
+                &WaitOptions::new().poll_interval(Duration::from_millis(1)),
+            )
+            .await
+            .expect("certificate is issued");
+
+        assert_eq!(mock.requests_for("ssl", "DescribeCertificate").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn async_client_certificate_waiter_stops_on_failure_status() {
+        let mock = MockTransport::new();
+        mock.on("ssl", "DescribeCertificate").respond(json!({
+            "CertificateId": "cert-1",
+            "Status": 2,
+            "StatusMsg": "CAA record check failed"
+        }));
+        let client = build_client(&mock);
+
+        let err = client
+            .ssl()
+            .wait_until_certificate_issued("cert-1", &WaitOptions::new())
+            .await
+            .expect_err("review failed");
+
+        assert_eq!(err.kind(), ErrorKind::WaiterFailed);
+        assert!(
+            err.to_string()
+                .contains("cert-1=2 (CAA record check failed)")
+        );
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn async_client_queues_requests_on_rate_limiter() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        mock.on("vpc", "DescribeVpcs")
+            .respond(json!({ "TotalCount": 0, "VpcSet": [] }));
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .rate_limiter(RateLimiter::new().service("cvm", RateLimit::per_second(20).burst(1)))
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        let started = std::time::Instant::now();
+        client
+            .vpc()
+            .describe_vpcs(&tencent_sdk::types::vpc::DescribeVpcsRequest::new())
+            .await
+            .expect("unlimited service is not queued");
+        let request = DescribeInstancesRequest::new();
+        for _ in 0..3 {
+            client
+                .cvm()
+                .describe_instances(&request)
+                .await
+                .expect("queued request succeeds");
+        }
+
+        assert!(started.elapsed() >= Duration::from_millis(90));
+        assert_eq!(mock.requests_for("cvm", "DescribeInstances").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn async_client_circuit_breaker_fails_fast_then_probes() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .fail(std::io::ErrorKind::ConnectionReset)
+            .respond_error("InternalError", "backend unavailable")
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        mock.on("vpc", "DescribeVpcs")
+            .respond(json!({ "TotalCount": 0, "VpcSet": [] }));
+        let breaker = std::sync::Arc::new(
+            CircuitBreaker::new()
+                .failure_threshold(2)
+                .cooldown(Duration::from_millis(50)),
+        );
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .retry(5, Duration::ZERO)
+            .circuit_breaker(breaker.clone())
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+        let request = DescribeInstancesRequest::new();
+
+        let err = client
+            .cvm()
+            .describe_instances(&request)
+            .await
+            .expect_err("circuit opens during the retry loop");
+        assert_eq!(err.code(), Some("InternalError"));
+        assert_eq!(mock.requests().len(), 2);
+        let host = err.host().expect("host").to_string();
+        assert_eq!(breaker.state(&host), CircuitState::Open);
+
+        let err = client
+            .cvm()
+            .describe_instances(&request)
+            .await
+            .expect_err("open circuit fails fast");
+        assert_eq!(err.kind(), ErrorKind::CircuitOpen);
+        assert!(err.retry_after().is_some());
+        assert_eq!(mock.requests().len(), 2);
+
+        client
+            .vpc()
+            .describe_vpcs(&tencent_sdk::types::vpc::DescribeVpcsRequest::new())
+            .await
+            .expect("other hosts are unaffected");
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let aborting = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .circuit_breaker(breaker.clone())
+            .interceptor(HeaderInterceptor(""))
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+        let err = aborting
+            .cvm()
+            .describe_instances(&request)
+            .await
+            .expect_err("interceptor aborts the probe");
+        assert_eq!(err.kind(), ErrorKind::InvalidConfig);
+        assert_eq!(breaker.state(&host), CircuitState::HalfOpen);
+
+        client
+            .cvm()
+            .describe_instances(&request)
+            .await
+            .expect("aborted probe frees the slot");
+        assert_eq!(breaker.state(&host), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn async_client_resolves_regional_and_pinned_endpoints() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        mock.on("vpc", "DescribeVpcs")
+            .respond(json!({ "TotalCount": 0, "VpcSet": [] }));
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .endpoint_mode(EndpointMode::Regional)
+            .service_endpoint("vpc", "vpc.internal.tencentcloudapi.com")
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().region("ap-shanghai"))
+            .await
+            .expect("regional request succeeds");
+        client
+            .vpc()
+            .describe_vpcs(&tencent_sdk::types::vpc::DescribeVpcsRequest::new())
+            .await
+            .expect("pinned request succeeds");
+
+        let requests = mock.requests();
+        assert_eq!(
+            requests[0].request().url(),
+            "https://cvm.ap-shanghai.tencentcloudapi.com/"
+        );
+        assert_eq!(
+            requests[0].request().headers()["host"],
+            "cvm.ap-shanghai.tencentcloudapi.com"
+        );
+        assert_eq!(
+            requests[1].request().url(),
+            "https://vpc.internal.tencentcloudapi.com/"
+        );
+        assert_eq!(
+            requests[1].request().headers()["host"],
+            "vpc.internal.tencentcloudapi.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn async_client_profile_endpoints_yield_to_regional_mode() {
+        let dir =
+            std::env::temp_dir().join(format!("tencent-sdk-mock-profile-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create profile dir");
+        std::fs::write(
+            dir.join("test.credential"),
+            json!({ "secretId": "profile_id", "secretKey": "profile_key" }).to_string(),
+        )
+        .expect("write credential file");
+        std::fs::write(
+            dir.join("test.configure"),
+            json!({
+                "_sys_param": { "region": "ap-shanghai" },
+                "cvm": { "endpoint": "cvm.tencentcloudapi.com" },
+                "vpc": { "endpoint": "vpc.ap-beijing.tencentcloudapi.com" }
+            })
+            .to_string(),
+        )
+        .expect("write configure file");
+
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        mock.on("vpc", "DescribeVpcs")
+            .respond(json!({ "TotalCount": 0, "VpcSet": [] }));
+        for mode in [EndpointMode::Regional, EndpointMode::ServiceSubdomain] {
+            let client = Client::builder_tencent_cloud()
+                .expect("build client builder")
+                .load_profile(ProfileProvider::new("test").with_config_dir(&dir))
+                .expect("load profile")
+                .endpoint_mode(mode)
+                .transport(mock.clone())
+                .build()
+                .expect("build client");
+            client
+                .cvm()
+                .describe_instances(&DescribeInstancesRequest::new())
+                .await
+                .expect("cvm request succeeds");
+            client
+                .vpc()
+                .describe_vpcs(&tencent_sdk::types::vpc::DescribeVpcsRequest::new())
+                .await
+                .expect("vpc request succeeds");
+        }
+
+        let hosts: Vec<_> = mock
+            .requests()
+            .iter()
+            .map(|request| request.request().headers()["host"].clone())
+            .collect();
+        assert_eq!(
+            hosts,
+            [
+                "cvm.ap-shanghai.tencentcloudapi.com",
+                "vpc.ap-shanghai.tencentcloudapi.com",
+                "cvm.tencentcloudapi.com",
+                "vpc.ap-beijing.tencentcloudapi.com",
+            ]
+        );
+        std::fs::remove_dir_all(&dir).expect("remove profile dir");
+    }
+
+    #[derive(Default)]
+    struct RecordingInterceptor {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RecordingInterceptor {
+        fn push(&self, hook: &str, context: &InterceptorContext<'_>) {
+            self.events.lock().unwrap().push(format!(
+                "{hook} {}.{} #{}",
+                context.service(),
+                context.action(),
+                context.attempt()
+            ));
+        }
+    }
+
+    impl Interceptor for RecordingInterceptor {
+        fn before_signing(
+            &self,
+            context: &InterceptorContext<'_>,
+            headers: &mut http::HeaderMap,
+        ) -> tencent_sdk::Result<()> {
+            self.push("before_signing", context);
+            headers.insert("x-audit-attempt", context.attempt().into());
+            Ok(())
+        }
+
+        fn after_signing(
+            &self,
+            context: &InterceptorContext<'_>,
+            request: &mut tencent_sdk::transport::TransportRequest,
+        ) -> tencent_sdk::Result<()> {
+            self.push("after_signing", context);
+            assert!(request.headers().contains_key("authorization"));
+            Ok(())
+        }
+
+        fn after_response(
+            &self,
+            context: &InterceptorContext<'_>,
+            _response: &tencent_sdk::transport::TransportResponse,
+        ) {
+            self.push("after_response", context);
+        }
+
+        fn on_error(&self, context: &InterceptorContext<'_>, _error: &Error) {
+            self.push("on_error", context);
+        }
+    }
+
+    #[tokio::test]
+    async fn async_client_calls_interceptors_for_each_attempt() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .fail(std::io::ErrorKind::ConnectionReset)
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        let interceptor = std::sync::Arc::new(RecordingInterceptor::default());
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .retry(1, Duration::ZERO)
+            .interceptor(interceptor.clone())
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new())
+            .await
+            .expect("retried request succeeds");
+
+        assert_eq!(
+            *interceptor.events.lock().unwrap(),
+            [
+                "before_signing cvm.DescribeInstances #1",
+                "after_signing cvm.DescribeInstances #1",
+                "on_error cvm.DescribeInstances #1",
+                "before_signing cvm.DescribeInstances #2",
+                "after_signing cvm.DescribeInstances #2",
+                "after_response cvm.DescribeInstances #2",
+            ]
+        );
+        let requests = mock.requests();
+        assert_eq!(requests[1].request().headers()["x-audit-attempt"], "2");
+    }
+
+    struct HeaderInterceptor(&'static str);
+
+    impl Interceptor for HeaderInterceptor {
+        fn before_signing(
+            &self,
+            _context: &InterceptorContext<'_>,
+            headers: &mut http::HeaderMap,
+        ) -> tencent_sdk::Result<()> {
+            if self.0.is_empty() {
+                return Err(Error::invalid_config_with_source(
+                    "audit log unavailable",
+                    std::io::Error::from(std::io::ErrorKind::NotConnected),
+                ));
+            }
+            headers.insert(self.0, http::HeaderValue::from_static("overridden"));
+            Ok(())
+        }
+    }
+
+    struct MissingCredentials;
+
+    impl tencent_sdk::CredentialsProvider for MissingCredentials {
+        fn credentials(&self) -> tencent_sdk::Result<tencent_sdk::Credentials> {
+            Err(Error::invalid_config_with_source(
+                "no credentials configured",
+                std::io::Error::from(std::io::ErrorKind::NotFound),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn async_client_reports_credential_failures_to_interceptors() {
+        let mock = MockTransport::new();
+        let recorder = std::sync::Arc::new(RecordingInterceptor::default());
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::provider(MissingCredentials))
+            .interceptor(recorder.clone())
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        let err = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new())
+            .await
+            .expect_err("credentials are missing");
+
+        assert_eq!(err.message(), Some("no credentials configured"));
+        assert!(mock.requests().is_empty());
+        assert_eq!(
+            recorder.events.lock().unwrap().last().map(String::as_str),
+            Some("on_error cvm.DescribeInstances #1")
+        );
+    }
+
+    #[tokio::test]
+    async fn async_client_reports_interceptor_failures_without_sending() {
+        for (header, message) in [
+            (
+                "content-type",
+                "interceptors must not set the signed `content-type` header",
+            ),
+            (
+                "x-tc-region",
+                "interceptors must not set the signed `x-tc-region` header",
+            ),
+            ("", "audit log unavailable"),
+        ] {
+            let mock = MockTransport::new();
+            let recorder = std::sync::Arc::new(RecordingInterceptor::default());
+            let client = Client::builder_tencent_cloud()
+                .expect("build client builder")
+                .auth(Auth::tc3("secret_id", "secret_key"))
+                .interceptor(HeaderInterceptor(header))
+                .interceptor(recorder.clone())
+                .transport(mock.clone())
+                .build()
+                .expect("build client");
+
+            let err = client
+                .cvm()
+                .describe_instances(&DescribeInstancesRequest::new())
+                .await
+                .expect_err("interceptor aborts the request");
+
+            assert_eq!(err.kind(), ErrorKind::InvalidConfig);
+            assert_eq!(err.message(), Some(message));
+            assert!(mock.requests().is_empty());
+            assert_eq!(
+                recorder.events.lock().unwrap().last().map(String::as_str),
+                Some("on_error cvm.DescribeInstances #1")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn async_client_returns_response_metadata() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .fail(std::io::ErrorKind::ConnectionReset)
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [], "RequestId": "req-meta" }));
+        let client = build_client(&mock);
+
+        let response = client
+            .execute_with_metadata(&DescribeInstancesRequest::new().region("ap-shanghai"), None)
+            .await
+            .expect("request succeeds");
+
+        let metadata = response.metadata();
+        assert_eq!(
+            metadata.request_id().map(|id| id.as_str()),
+            Some("req-meta")
+        );
+        assert_eq!(metadata.status(), StatusCode::OK);
+        assert_eq!(metadata.attempts(), 2);
+        assert_eq!(metadata.host(), "cvm.tencentcloudapi.com");
+        assert_eq!(metadata.region().map(|r| r.as_str()), Some("ap-shanghai"));
+        assert!(metadata.elapsed() > Duration::ZERO);
+        assert_eq!(response.into_output().response.total_count, Some(0));
+    }
+
+    #[tokio::test]
+    async fn async_client_uses_per_request_retry_policy() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .fail(std::io::ErrorKind::ConnectionReset)
+            .fail(std::io::ErrorKind::ConnectionReset)
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        let client = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .retry_policy(NoRetryPolicy)
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+        let request = DescribeInstancesRequest::new();
+
+        let err = client
+            .cvm()
+            .describe_instances(&request)
+            .await
+            .expect_err("client policy never retries");
+        assert_eq!(err.kind(), ErrorKind::Transport);
+
+        let options =
+            RequestOptions::new().retry_policy(StandardRetryPolicy::new(1, Duration::ZERO));
+        client
+            .cvm()
+            .describe_instances_with_options(&request, &options)
+            .await
+            .expect("request policy retries once");
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn async_client_fails_unscripted_actions() {
+        let mock = MockTransport::new();
+        let client = build_client(&mock);
+
+        let err = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new())
+            .await
+            .expect_err("no reply scripted");
+
+        assert_eq!(err.kind(), ErrorKind::Transport);
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_client_records_and_replays_cassette() {
+        use wiremock::matchers::{header, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("X-TC-Action", "DescribeInstances"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-TC-RequestId", "req-header")
+                    .set_body_json(json!({
+                        "Response": {
+                            "TotalCount": 0,
+                            "InstanceSet": [],
+                            "RequestId": "req-recorded"
+                        }
+                    })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let path = std::env::temp_dir().join(format!(
+            "tencent-sdk-cassette-{}/describe_instances.json",
+            std::process::id()
+        ));
+        let recorder = Client::builder(server.uri())
+            .expect("build client builder")
+            .endpoint_mode(tencent_sdk::client::EndpointMode::FixedHost)
+            .auth(Auth::provider(
+                tencent_sdk::Credentials::new("AKIDrecorded", "secret_key")
+                    .with_token("session-token"),
+            ))
+            .no_system_proxy(true)
+            .record_cassette(&path)
+            .build()
+            .expect("build recording client");
+        recorder
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await
+            .expect("recorded request succeeds");
+
+        let cassette = std::fs::read_to_string(&path).expect("cassette written");
+        assert!(cassette.contains("DescribeInstances"));
+        assert!(cassette.contains("req-header"));
+        assert!(!cassette.contains("AKIDrecorded"));
+        assert!(!cassette.contains("session-token"));
+
+        let replayer = Client::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .replay_cassette(&path)
+            .expect("open cassette")
+            .build()
+            .expect("build replaying client");
+        let resp = replayer
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new().limit(1))
+            .await
+            .expect("replayed request succeeds");
+        assert_eq!(resp.response.request_id.as_str(), "req-recorded");
+
+        let err = replayer
+            .tag()
+            .describe_projects(&tencent_sdk::types::tag::DescribeProjectsRequest::new())
+            .await
+            .expect_err("tag was never recorded");
+        assert_eq!(err.kind(), ErrorKind::Transport);
+        std::fs::remove_dir_all(path.parent().unwrap()).expect("remove cassette dir");
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_client {
+    use http::StatusCode;
+    use serde_json::json;
+    use std::time::Duration;
+    use tencent_sdk::client::{
+        EndpointMode, Interceptor, InterceptorContext, RateLimit, RateLimiter, RetryContext,
+        RetryPolicy, WaitOptions,
+    };
+    use tencent_sdk::testing::MockTransport;
+    use tencent_sdk::types::cvm::{DescribeInstancesRequest, StartInstancesRequest};
+    use tencent_sdk::types::vpc::DescribeVpcsRequest;
+    use tencent_sdk::{Auth, BlockingClient};
+
+    #[test]
+    fn blocking_client_replays_scripted_replies() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .fail(std::io::ErrorKind::TimedOut)
+            .respond(json!({ "TotalCount": 0, "InstanceSet": [] }));
+        let client = BlockingClient::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .retry(1, Duration::ZERO)
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        let resp = client
+            .cvm()
+            .describe_instances(&DescribeInstancesRequest::new())
+            .expect("request succeeds after retry");
+
+        assert_eq!(resp.response.total_count, Some(0));
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(mock.requests()[1].service(), "cvm");
+    }
+
+    #[test]
+    fn blocking_client_paginates_vpcs() {
+        let mock = MockTransport::new();
+        mock.on("vpc", "DescribeVpcs")
+            .respond(
+                json!({ "TotalCount": 3, "VpcSet": [{ "VpcId": "vpc-1" }, { "VpcId": "vpc-2" }] }),
+            )
+            .respond(json!({ "TotalCount": 3, "VpcSet": [{ "VpcId": "vpc-3" }] }));
+        let client = BlockingClient::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .default_region("ap-guangzhou")
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        let vpcs = client
+            .paginate(DescribeVpcsRequest::new())
+            .page_size(2)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("all pages succeed");
+
+        assert_eq!(vpcs.len(), 3);
+        assert_eq!(vpcs[2].vpc_id.as_ref().unwrap().as_str(), "vpc-3");
+        assert_eq!(mock.requests_for("vpc", "DescribeVpcs").len(), 2);
+    }
+
+    #[test]
+    fn blocking_client_waits_until_instances_are_gone() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "DescribeInstances")
+            .respond(json!({
+                "TotalCount": 1,
+                "InstanceSet": [{ "InstanceId": "ins-1", "InstanceState": "SHUTDOWN" }]
+            }))
+            .respond_error("InvalidInstanceId.NotFound", "instance not found");
+        let client = BlockingClient::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        client
+            .cvm()
+            .wait_until_terminated(
+                "ap-guangzhou",
+                ["ins-1"],
+                &WaitOptions::new().poll_interval(Duration::from_millis(1)),
+            )
+            .expect("instance disappears");
+
+        assert_eq!(mock.requests_for("cvm", "DescribeInstances").len(), 2);
+    }
+
+    #[derive(Debug)]
+    struct RetryMutationsOnce;
+
+    impl RetryPolicy for RetryMutationsOnce {
+        fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+            (context.attempt() == 1 && !context.is_idempotent()).then_some(Duration::ZERO)
+        }
+    }
+
+    #[test]
+    fn blocking_client_applies_custom_retry_policy() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "StartInstances")
+            .respond_status(StatusCode::SERVICE_UNAVAILABLE, "busy")
+            .respond(json!({}));
+        let client = BlockingClient::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .retry_policy(RetryMutationsOnce)
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        client
+            .cvm()
+            .start_instances(&StartInstancesRequest::new("ap-guangzhou", ["ins-1"]))
+            .expect("mutation retried once");
+
+        assert_eq!(mock.requests_for("cvm", "StartInstances").len(), 2);
+    }
+
+    #[test]
+    fn blocking_client_shares_rate_limiter_per_action_and_region() {
+        let mock = MockTransport::new();
+        mock.on("cvm", "StartInstances").respond(json!({}));
+        let limiter = std::sync::Arc::new(
+            RateLimiter::new()
+                .action("cvm", "StartInstances", RateLimit::per_second(20).burst(1))
+                .per_region(true),
+        );
+        let build = || {
+            BlockingClient::builder_tencent_cloud()
+                .expect("build client builder")
+                .auth(Auth::tc3("secret_id", "secret_key"))
+                .rate_limiter(limiter.clone())
+                .transport(mock.clone())
+                .build()
+                .expect("build client")
+        };
+        let (first, second) = (build(), build());
+
+        let started = std::time::Instant::now();
+        first
+            .cvm()
+            .start_instances(&StartInstancesRequest::new("ap-shanghai", ["ins-1"]))
+            .expect("other region has its own bucket");
+        for client in [&first, &second, &first] {
+            client
+                .cvm()
+                .start_instances(&StartInstancesRequest::new("ap-guangzhou", ["ins-1"]))
+                .expect("queued request succeeds");
+        }
+
+        assert!(started.elapsed() >= Duration::from_millis(90));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(mock.requests_for("cvm", "StartInstances").len(), 4);
+    }
+
+    #[test]
+    fn blocking_client_presets_target_intl_and_internal_domains() {
+        let mock = MockTransport::new();
+        mock.on("vpc", "DescribeVpcs")
+            .respond(json!({ "TotalCount": 0, "VpcSet": [] }));
+
+        for (builder, host) in [
+            (
+                BlockingClient::builder_tencent_cloud_intl(),
+                "vpc.intl.tencentcloudapi.com",
+            ),
+            (
+                BlockingClient::builder_tencent_cloud_internal(),
+                "vpc.internal.tencentcloudapi.com",
+            ),
+        ] {
+            let client = builder
+                .expect("build client builder")
+                .auth(Auth::tc3("secret_id", "secret_key"))
+                .default_region("ap-singapore")
+                .transport(mock.clone())
+                .build()
+                .expect("build client");
+            client
+                .vpc()
+                .describe_vpcs(&DescribeVpcsRequest::new())
+                .expect("request succeeds");
+
+            let request = mock.requests().pop().expect("request sent");
+            assert_eq!(request.request().url(), format!("https://{host}/"));
+        }
+
+        let err = BlockingClient::builder_tencent_cloud_internal()
+            .expect("build client builder")
+            .endpoint_mode(EndpointMode::Regional)
+            .build()
+            .err()
+            .expect("regional mode is rejected");
+        assert_eq!(err.kind(), tencent_sdk::error::ErrorKind::InvalidConfig);
+    }
+
+    #[test]
+    fn blocking_client_interceptors_alter_requests_and_see_errors() {
+        struct TagAndCount(std::sync::atomic::AtomicUsize);
+
+        impl Interceptor for TagAndCount {
+            fn after_signing(
+                &self,
+                _context: &InterceptorContext<'_>,
+                request: &mut tencent_sdk::transport::TransportRequest,
+            ) -> tencent_sdk::Result<()> {
+                request
+                    .headers_mut()
+                    .insert("x-request-source", "batch".parse().unwrap());
+                Ok(())
+            }
+
+            fn on_error(&self, context: &InterceptorContext<'_>, error: &tencent_sdk::Error) {
+                assert!(!context.is_idempotent());
+                assert_eq!(error.code(), Some("InvalidInstance.NotSupported"));
+                self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+
+        let mock = MockTransport::new();
+        mock.on("cvm", "StartInstances")
+            .respond_error("InvalidInstance.NotSupported", "not supported");
+        let interceptor = std::sync::Arc::new(TagAndCount(Default::default()));
+        let client = BlockingClient::builder_tencent_cloud()
+            .expect("build client builder")
+            .auth(Auth::tc3("secret_id", "secret_key"))
+            .interceptor(interceptor.clone())
+            .transport(mock.clone())
+            .build()
+            .expect("build client");
+
+        client
+            .cvm()
+            .start_instances(&StartInstancesRequest::new("ap-guangzhou", ["ins-1"]))
+            .expect_err("api error");
+
+        assert_eq!(interceptor.0.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(
+            mock.requests()[0].request().headers()["x-request-source"],
+            "batch"
+        );
+    }
+}